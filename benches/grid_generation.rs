@@ -0,0 +1,46 @@
+extern crate sudoku_generator;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use sudoku_generator::generators::full_grid_generator::FullGridGenerator;
+use sudoku_generator::generators::puzzle_generator::PuzzleGenerator;
+use sudoku_generator::grid_transform::canonical_form;
+use sudoku_generator::sudoku_grid::SudokuGrid;
+
+// Runs the generator for the duration and prints how many grids it produced per second.
+fn measure(name: &str, duration: Duration, mut generate: impl FnMut()) {
+    let start = Instant::now();
+    let mut grids = 0;
+    while start.elapsed() < duration {
+        generate();
+        grids += 1;
+    }
+    let seconds = start.elapsed().as_secs_f64();
+    println!("{:<32} {:>10} grids {:>12.0} grids/s", name, grids, grids as f64 / seconds);
+}
+
+fn main() {
+    let duration = Duration::from_secs(3);
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    measure("FullGridGenerator digits", duration, || {
+        black_box(FullGridGenerator::generate_digits(&mut rng));
+    });
+    measure("FullGridGenerator grid", duration, || {
+        black_box(FullGridGenerator::generate(&mut rng));
+    });
+    measure("create_sudoku_grid_with_rng", duration, || {
+        black_box(SudokuGrid::create_sudoku_grid_with_rng(&mut rng));
+    });
+
+    let puzzle = PuzzleGenerator::new().generate_with_seed(0).puzzle;
+    measure("canonical_form solution", duration, || {
+        black_box(canonical_form(&FullGridGenerator::generate(&mut rng)));
+    });
+    measure("canonical_form puzzle", duration, || {
+        black_box(canonical_form(&puzzle));
+    });
+}
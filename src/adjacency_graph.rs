@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
+use crate::solvers::sudoku_solver::SolverError;
 use crate::sudoku_grid::SudokuGrid;
 
 pub struct AdjacencyGraph {
@@ -15,6 +16,8 @@ pub enum BiColor {
     None,
 }
 
+pub type BiColoredGraph = HashMap<(usize, usize, usize), BiColor>;
+
 impl AdjacencyGraph {
     // Creates a new empty graph.
     pub fn new() -> Self {
@@ -90,8 +93,9 @@ impl AdjacencyGraph {
         }
     }
 
-    pub fn bicolor_graphs(graph: &AdjacencyGraph) -> Vec<HashMap<(usize, usize, usize), BiColor>> {
-        let mut ret:Vec<HashMap<(usize, usize, usize), BiColor>> = Vec::new();
+    // Fails if any connected part of the graph is not bipartite.
+    pub fn bicolor_graphs(graph: &AdjacencyGraph) -> Result<Vec<BiColoredGraph>, SolverError> {
+        let mut ret: Vec<BiColoredGraph> = Vec::new();
         for node in graph.nodes() {
             let mut found = false;
             for colored_graph in &ret {
@@ -101,15 +105,15 @@ impl AdjacencyGraph {
                 }
             }
             if !found {
-                ret.push(AdjacencyGraph::bicolor_graph(graph, &node));
+                ret.push(AdjacencyGraph::bicolor_graph(graph, &node)?);
             }
         }
 
-        return ret;
+        return Ok(ret);
     }
 
-    // Asumes a bipartite graph is given and will panic if not.
-    fn bicolor_graph(graph: &AdjacencyGraph, start_node: &(usize, usize, usize)) -> HashMap<(usize, usize, usize), BiColor> {
+    // Colors the graph connected to the start node, returns an error if it is not bipartite.
+    fn bicolor_graph(graph: &AdjacencyGraph, start_node: &(usize, usize, usize)) -> Result<BiColoredGraph, SolverError> {
         let mut colors: HashMap<(usize, usize, usize), BiColor> = HashMap::new();
         let mut queue: VecDeque<(usize, usize, usize)> = VecDeque::new();
 
@@ -131,13 +135,13 @@ impl AdjacencyGraph {
                         colors.insert(neighbor, opposite_color);
                         queue.push_back(neighbor);
                     } else if colors[&neighbor] == current_color {
-                        panic!("Graph is not bipartite!");
+                        return Err(SolverError::NotBipartite(neighbor));
                     }
                 }
             }
         }
 
-        colors
+        Ok(colors)
     }
 
     pub fn to_unique_pairs(&self) -> Vec<((usize, usize, usize), (usize, usize, usize))> {
@@ -0,0 +1,86 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::png::{self, Frame};
+use crate::png_renderer::PngRenderer;
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+
+// Turns the stages of a solve into an animated PNG, each frame is drawn by the PngRenderer.
+pub struct AnimationExporter {
+    pub renderer: PngRenderer,
+    pub frame_delay: Duration,
+    // The last frame shows the finished board, it is held longer so the result can be seen before the animation repeats.
+    pub final_delay: Duration,
+    // Whether the boards before and after each step are frames, without them only the steps are shown.
+    pub include_boards: bool,
+    // Number of times the animation plays, 0 repeats forever.
+    pub loops: u32,
+}
+
+impl AnimationExporter {
+    pub fn new(renderer: PngRenderer) -> Self {
+        Self {
+            renderer,
+            frame_delay: Duration::from_millis(1500),
+            final_delay: Duration::from_millis(4000),
+            include_boards: true,
+            loops: 0,
+        }
+    }
+
+    pub fn set_frame_delay(&mut self, frame_delay: Duration) {
+        self.frame_delay = frame_delay;
+    }
+
+    pub fn set_final_delay(&mut self, final_delay: Duration) {
+        self.final_delay = final_delay;
+    }
+
+    pub fn set_include_boards(&mut self, include_boards: bool) {
+        self.include_boards = include_boards;
+    }
+
+    pub fn set_loops(&mut self, loops: u32) {
+        self.loops = loops;
+    }
+
+    // The visualizers shown, in order. The last board is always kept so the animation ends on the result.
+    pub fn frames<'a>(&self, visualizers_per_step: &'a [Vec<SudokuVisualizerBuilder>]) -> Vec<&'a SudokuVisualizerBuilder> {
+        let mut frames: Vec<&SudokuVisualizerBuilder> = Vec::new();
+        for visualizers in visualizers_per_step {
+            for (stage, visualizer) in visualizers.iter().enumerate() {
+                let is_board = stage == 0 || stage == visualizers.len() - 1;
+                if self.include_boards || !is_board {
+                    frames.push(visualizer);
+                }
+            }
+        }
+        if let Some(last_board) = visualizers_per_step.last().and_then(|visualizers| visualizers.last()) {
+            if !frames.last().is_some_and(|&frame| std::ptr::eq(frame, last_board)) {
+                frames.push(last_board);
+            }
+        }
+        frames
+    }
+
+    // Without any stages the image is a single frame of an empty board.
+    pub fn render(&self, visualizers_per_step: &[Vec<SudokuVisualizerBuilder>]) -> Vec<u8> {
+        let delay_ms = |delay: Duration| delay.as_millis().min(u16::MAX as u128) as u16;
+        let empty_board = SudokuVisualizerBuilder::new();
+        let mut visualizers = self.frames(visualizers_per_step);
+        if visualizers.is_empty() {
+            visualizers.push(&empty_board);
+        }
+        let frames: Vec<Frame> = visualizers.iter().enumerate().map(|(index, visualizer)| Frame {
+            rgb: self.renderer.render_canvas(visualizer).pixels,
+            delay_ms: if index == visualizers.len() - 1 { delay_ms(self.final_delay) } else { delay_ms(self.frame_delay) },
+        }).collect();
+        png::encode_apng(self.renderer.width as usize, self.renderer.height as usize, &frames, self.loops)
+    }
+
+    pub fn save(&self, visualizers_per_step: &[Vec<SudokuVisualizerBuilder>], path: &Path) -> io::Result<()> {
+        fs::write(path, self.render(visualizers_per_step))
+    }
+}
@@ -0,0 +1,129 @@
+use crate::color::Color;
+use crate::sudoku_grid::SudokuGrid;
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::{HighlightRole, Theme};
+
+// Renders visualizers as text for terminals, using 24 bit ANSI colours from the same themes the raylib window draws with.
+pub struct AnsiRenderer {
+    // Without colour only the layout is printed, for logs and terminals that do not support it.
+    pub color: bool,
+    pub theme: Theme,
+}
+
+impl AnsiRenderer {
+    pub fn new(color: bool) -> Self {
+        Self {
+            color,
+            theme: Theme::light(),
+        }
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn paint(&self, text: &str, foreground: Option<Color>, background: Option<Color>) -> String {
+        if !self.color || (foreground.is_none() && background.is_none()) {
+            return text.to_string();
+        }
+        let mut painted = String::new();
+        if let Some(color) = foreground {
+            painted.push_str(&format!("\x1b[1;38;2;{};{};{}m", color.r, color.g, color.b));
+        }
+        if let Some(color) = background {
+            painted.push_str(&format!("\x1b[48;2;{};{};{}m", color.r, color.g, color.b));
+        }
+        painted.push_str(text);
+        painted.push_str("\x1b[0m");
+        painted
+    }
+
+    // Plain candidates keep the colour of the terminal so they stay readable on any background.
+    fn foreground(&self, role: HighlightRole) -> Option<Color> {
+        if role == HighlightRole::Candidate { None } else { Some(self.theme.foreground(role)) }
+    }
+
+    // One of the three text rows of a cell, 5 characters wide like the Display of SudokuGrid.
+    fn format_cell(&self, builder: &SudokuVisualizerBuilder, row: usize, col: usize, sub_row: usize) -> String {
+        let cell_background = builder.cell_highlights.get(&(row, col)).map(|&role| self.theme.fill(role));
+        if let Some(&(digit, role)) = builder.digits.get(&(row, col)) {
+            if sub_row == 1 {
+                return format!(
+                    "{}{}{}",
+                    self.paint("  ", None, cell_background),
+                    self.paint(&digit.to_string(), self.foreground(role), cell_background),
+                    self.paint("  ", None, cell_background)
+                );
+            }
+            return self.paint("     ", None, cell_background);
+        }
+
+        let mut cell = String::new();
+        for num in sub_row * 3 + 1..=sub_row * 3 + 3 {
+            match builder.candidates.get(&(row, col, num)) {
+                Some(&role) => {
+                    let background = builder.candidates_highlights.get(&(row, col, num)).map(|&role| self.theme.fill(role)).or(cell_background);
+                    cell.push_str(&self.paint(&num.to_string(), self.foreground(role), background));
+                },
+                None => cell.push_str(&self.paint(".", None, cell_background)),
+            }
+        }
+        cell.push_str(&self.paint("  ", None, cell_background));
+        cell
+    }
+
+    pub fn render_grid(&self, builder: &SudokuVisualizerBuilder) -> String {
+        let mut text = String::new();
+        text.push_str("     1     2     3  |  4     5     6  |  7     8     9  \n");
+        text.push_str("--------------------+-----------------+----------------\n");
+        for row in 0..9 {
+            for sub_row in 0..3 {
+                if sub_row == 1 {
+                    text.push_str(&format!("{} |", (b'A' + row as u8) as char));
+                } else {
+                    text.push_str("  |");
+                }
+                for col in 0..9 {
+                    text.push_str(&self.format_cell(builder, row, col, sub_row));
+                    text.push(if col % 3 == 2 && col != 8 { '|' } else { ' ' });
+                }
+                text.push('\n');
+            }
+            if row % 3 == 2 && row != 8 {
+                text.push_str("--------------------+-----------------+----------------\n");
+            }
+        }
+        text
+    }
+
+    // Title, description, grid and a list of the chain links of one visualizer.
+    pub fn render_step(&self, builder: &SudokuVisualizerBuilder) -> String {
+        let mut text = String::new();
+        if self.color {
+            text.push_str(&format!("\x1b[1m{}\x1b[0m\n", builder.title));
+        } else {
+            text.push_str(&format!("{}\n", builder.title));
+        }
+        if builder.description != "-" {
+            text.push_str(&builder.description);
+            text.push('\n');
+        }
+        text.push_str(&self.render_grid(builder));
+
+        let mut chains: Vec<_> = builder.chains.iter().collect();
+        chains.sort_by_key(|(&link, _)| link);
+        for (&((row_from, col_from, num_from), (row_to, col_to, num_to)), &role) in chains {
+            // Links stored in both directions are listed once
+            let reversed = ((row_to, col_to, num_to), (row_from, col_from, num_from));
+            if builder.chains.contains_key(&reversed) && reversed < ((row_from, col_from, num_from), (row_to, col_to, num_to)) { continue; }
+            let link = format!(
+                "Chain {} ({}) - {} ({})",
+                SudokuGrid::cell_to_str((row_from, col_from)), num_from,
+                SudokuGrid::cell_to_str((row_to, col_to)), num_to
+            );
+            text.push_str(&self.paint(&link, self.foreground(role), None));
+            text.push('\n');
+        }
+        text
+    }
+}
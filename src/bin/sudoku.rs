@@ -0,0 +1,556 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write};
+use std::num::NonZeroU32;
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use sudoku_generator::animation_exporter::AnimationExporter;
+use sudoku_generator::ansi_renderer::AnsiRenderer;
+use sudoku_generator::generators::symmetry::Symmetry;
+use sudoku_generator::generators::targeted_generator::TargetedGenerator;
+use sudoku_generator::html_report::HtmlReport;
+use sudoku_generator::png_renderer::PngRenderer;
+use sudoku_generator::solvers::difficulty::{self, Difficulty};
+use sudoku_generator::solvers::solver_manager::{SolveSummary, SudokuSolverManager};
+use sudoku_generator::sudoku_grid::SudokuGrid;
+use sudoku_generator::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use sudoku_generator::svg_renderer::SvgRenderer;
+use sudoku_generator::theme::Theme;
+
+const USAGE: &str = "Usage:
+  sudoku generate [options]
+    --count N               Number of puzzles to generate (default 10)
+    --output FILE           File to write to (default stdout)
+    --format csv|jsonl      Output format (default csv)
+    --symmetry NAME         none, rotational180, rotational90, diagonal, antidiagonal, horizontal or vertical (default none)
+    --difficulty TIER       beginner, easy, medium, hard or expert (default any tier)
+    --clues MIN-MAX         Range of clue counts (default any count)
+    --seed N                Seed for reproducible output (default random)
+    --threads N             Worker threads (default all cores)
+    --attempts N            Generation attempts per puzzle before giving up (default 1000)
+  sudoku solve [options]
+    --input FILE            File with one puzzle per line, 0 or . for empty cells (default stdin)
+    --output FILE           File to write to (default stdout)
+    --format text|json      Output format, json writes one object per line (default text)
+    --profile TIER          Only use techniques up to beginner, easy, medium, hard or expert (default all)
+  sudoku explain [options]
+    --puzzle PUZZLE         Puzzle to explain (default the first puzzle of --input)
+    --input FILE            File to read the puzzle from (default stdin)
+    --profile TIER          Only use techniques up to beginner, easy, medium, hard or expert (default all)
+    --color auto|always|never  ANSI colours, auto uses them on terminals unless NO_COLOR is set (default auto)
+    --theme NAME            light, dark or colorblind colours (default light)
+  sudoku export [options]
+    --output DIR            Directory to write step-STEP-STAGE images to
+    --puzzle PUZZLE         Puzzle to export (default the first puzzle of --input)
+    --input FILE            File to read the puzzle from (default stdin)
+    --profile TIER          Only use techniques up to beginner, easy, medium, hard or expert (default all)
+    --format svg|png        Image format (default svg)
+    --width N, --height N   Image size in pixels (default 800)
+    --theme NAME            light, dark or colorblind colours (default light)
+  sudoku animate [options]
+    --output FILE           Animated PNG to write
+    --puzzle PUZZLE         Puzzle to animate (default the first puzzle of --input)
+    --input FILE            File to read the puzzle from (default stdin)
+    --profile TIER          Only use techniques up to beginner, easy, medium, hard or expert (default all)
+    --stages all|steps      Show the boards between steps or only the steps (default all)
+    --delay MS              Time each frame is shown (default 1500)
+    --final-delay MS        Time the solved board is shown (default 4000)
+    --loops N               Times the animation plays, 0 repeats forever (default 0)
+    --width N, --height N   Image size in pixels (default 800)
+    --theme NAME            light, dark or colorblind colours (default light)
+  sudoku report [options]
+    --puzzle PUZZLE         Puzzle to report on (default the first puzzle of --input)
+    --input FILE            File to read the puzzle from (default stdin)
+    --output FILE           HTML file to write (default stdout)
+    --profile TIER          Only use techniques up to beginner, easy, medium, hard or expert (default all)
+    --theme NAME            light, dark or colorblind colours (default light)";
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+enum OutputFormat {
+    Csv,
+    JsonLines,
+}
+
+// One line of output.
+struct PuzzleRecord {
+    puzzle: String,
+    solution: String,
+    rating: String,
+    hardest_technique: String,
+    seed: u64,
+}
+
+impl PuzzleRecord {
+    const CSV_HEADER: &'static str = "puzzle,solution,rating,hardest_technique,seed";
+
+    fn to_line(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Csv => format!("{},{},{},{},{}", self.puzzle, self.solution, self.rating, self.hardest_technique, self.seed),
+            OutputFormat::JsonLines => format!(
+                "{{\"puzzle\":{},\"solution\":{},\"rating\":{},\"hardest_technique\":{},\"seed\":{}}}",
+                json_string(&self.puzzle), json_string(&self.solution), json_string(&self.rating), json_string(&self.hardest_technique), self.seed
+            ),
+        }
+    }
+}
+
+// Result of solving one input line.
+struct SolveRecord {
+    line: usize,
+    puzzle: String,
+    // Why the puzzle could not be solved at all, None when it has a unique solution.
+    error: Option<String>,
+    solution: Option<String>,
+    summary: Option<SolveSummary>,
+}
+
+impl SolveRecord {
+    fn to_text(&self) -> String {
+        let mut text = format!("Line {}: {}\n", self.line, self.puzzle);
+        if let Some(error) = &self.error {
+            text.push_str(&format!("  Error: {}\n", error));
+        }
+        if let Some(solution) = &self.solution {
+            text.push_str(&format!("  Solution: {}\n", solution));
+        }
+        if let Some(summary) = &self.summary {
+            let rating = summary.difficulty().map_or("None".to_string(), |difficulty| difficulty.to_string());
+            text.push_str(&format!("  Solved by techniques: {}\n", if summary.solved { "yes" } else { "no" }));
+            text.push_str(&format!("  Steps: {}\n", summary.steps));
+            text.push_str(&format!("  Rating: {}\n", rating));
+            text.push_str(&format!("  Techniques: {}\n", summary.techniques_used.join(", ")));
+        }
+        text
+    }
+
+    fn to_json(&self) -> String {
+        let optional = |value: &Option<String>| value.as_deref().map_or("null".to_string(), json_string);
+        let mut fields = vec![
+            format!("\"line\":{}", self.line),
+            format!("\"puzzle\":{}", json_string(&self.puzzle)),
+            format!("\"error\":{}", optional(&self.error)),
+            format!("\"solution\":{}", optional(&self.solution)),
+        ];
+        if let Some(summary) = &self.summary {
+            let rating = summary.difficulty().map(|difficulty| difficulty.to_string());
+            let techniques: Vec<String> = summary.techniques_used.iter().map(|technique| json_string(technique)).collect();
+            fields.push(format!("\"solved_by_techniques\":{}", summary.solved));
+            fields.push(format!("\"steps\":{}", summary.steps));
+            fields.push(format!("\"rating\":{}", optional(&rating)));
+            fields.push(format!("\"techniques\":[{}]", techniques.join(",")));
+        }
+        format!("{{{}}}", fields.join(","))
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut escaped = String::from("\"");
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            ch if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+// Reads "--name value" pairs, every option takes a value.
+fn parse_options(args: &[String]) -> Result<HashMap<String, String>, String> {
+    let mut options = HashMap::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let Some(name) = arg.strip_prefix("--") else {
+            return Err(format!("Unexpected argument \"{}\".", arg));
+        };
+        let value = args.next().ok_or_else(|| format!("Missing value for --{}.", name))?;
+        options.insert(name.to_string(), value.clone());
+    }
+    Ok(options)
+}
+
+fn parse_number<T: std::str::FromStr>(options: &HashMap<String, String>, name: &str, default: T) -> Result<T, String> {
+    match options.get(name) {
+        Some(value) => value.parse().map_err(|_| format!("Invalid number \"{}\" for --{}.", value, name)),
+        None => Ok(default),
+    }
+}
+
+// Image width or height in pixels, 0 and negative sizes are rejected.
+fn parse_size(options: &HashMap<String, String>, name: &str) -> Result<i32, String> {
+    let size: NonZeroU32 = parse_number(options, name, NonZeroU32::new(800).unwrap())?;
+    i32::try_from(size.get()).map_err(|_| format!("Invalid number \"{}\" for --{}.", size, name))
+}
+
+fn open_output(options: &HashMap<String, String>) -> Result<Box<dyn Write>, String> {
+    match options.get("output") {
+        Some(path) => {
+            let file = File::create(path).map_err(|error| format!("Unable to create {}: {}", path, error))?;
+            Ok(Box::new(BufWriter::new(file)))
+        },
+        None => Ok(Box::new(BufWriter::new(io::stdout()))),
+    }
+}
+
+fn parse_format(options: &HashMap<String, String>) -> Result<OutputFormat, String> {
+    match options.get("format").map(String::as_str) {
+        None | Some("csv") => Ok(OutputFormat::Csv),
+        Some("jsonl") => Ok(OutputFormat::JsonLines),
+        Some(other) => Err(format!("Unknown format \"{}\".", other)),
+    }
+}
+
+fn create_generator(options: &HashMap<String, String>) -> Result<TargetedGenerator, String> {
+    let mut generator = match options.get("difficulty") {
+        Some(name) => TargetedGenerator::for_difficulty(Difficulty::from_name(name).ok_or_else(|| format!("Unknown difficulty \"{}\".", name))?),
+        None => TargetedGenerator::new(),
+    };
+
+    if let Some(name) = options.get("symmetry") {
+        generator.set_symmetry(Symmetry::from_name(name).ok_or_else(|| format!("Unknown symmetry \"{}\".", name))?);
+    }
+
+    if let Some(range) = options.get("clues") {
+        let invalid = || format!("Invalid clue range \"{}\", expected MIN-MAX.", range);
+        let (min, max) = range.split_once('-').ok_or_else(invalid)?;
+        let (min, max): (usize, usize) = (min.parse().map_err(|_| invalid())?, max.parse().map_err(|_| invalid())?);
+        if min > max || max > 81 { return Err(invalid()); }
+        generator.set_clue_range(min, max);
+    }
+
+    generator.set_max_attempts(parse_number(options, "attempts", 1000)?);
+    Ok(generator)
+}
+
+// Every puzzle gets its own seed from the stream of its index, so the output does not depend on the thread count.
+fn puzzle_seed(seed: u64, index: usize) -> u64 {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(index as u64);
+    rng.next_u64()
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let count: usize = parse_number(&options, "count", 10)?;
+    let seed: u64 = parse_number(&options, "seed", rand::random())?;
+    let default_threads = thread::available_parallelism().map(|threads| threads.get()).unwrap_or(1);
+    let threads: usize = parse_number(&options, "threads", default_threads)?.max(1);
+    let format = parse_format(&options)?;
+    let generator = create_generator(&options)?;
+    let mut output = open_output(&options)?;
+
+    let mut records: Vec<(usize, Option<PuzzleRecord>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|worker| {
+            let generator = &generator;
+            scope.spawn(move || {
+                (worker..count).step_by(threads).map(|index| {
+                    let record = generator.generate_with_seed(puzzle_seed(seed, index)).map(|(generated, summary)| PuzzleRecord {
+                        puzzle: generated.puzzle.to_number_string(),
+                        solution: generated.solution.to_number_string(),
+                        rating: summary.difficulty().map_or("Unrated".to_string(), |difficulty| difficulty.to_string()),
+                        hardest_technique: summary.hardest_technique().unwrap_or("None").to_string(),
+                        seed: generated.seed,
+                    });
+                    (index, record)
+                }).collect::<Vec<_>>()
+            })
+        }).collect();
+        workers.into_iter().flat_map(|worker| worker.join().expect("Generator thread panicked")).collect()
+    });
+    records.sort_by_key(|(index, _)| *index);
+
+    let write_error = |error: io::Error| format!("Unable to write output: {}", error);
+    if format == OutputFormat::Csv {
+        writeln!(output, "{}", PuzzleRecord::CSV_HEADER).map_err(write_error)?;
+    }
+    let mut failed = 0;
+    for (index, record) in records {
+        match record {
+            Some(record) => writeln!(output, "{}", record.to_line(format)).map_err(write_error)?,
+            None => {
+                eprintln!("Puzzle {} not found within the attempt limit (seed {}).", index, puzzle_seed(seed, index));
+                failed += 1;
+            },
+        }
+    }
+    output.flush().map_err(write_error)?;
+    eprintln!("Generated {} of {} puzzles with seed {}.", count - failed, count, seed);
+    Ok(())
+}
+
+// Reads the puzzle, checks it has exactly one solution and runs the techniques of the profile on it.
+fn solve_puzzle(line: usize, puzzle: &str, profile: Option<Difficulty>) -> SolveRecord {
+    let mut record = SolveRecord {
+        line,
+        puzzle: puzzle.to_string(),
+        error: None,
+        solution: None,
+        summary: None,
+    };
+
+    let sgrid = match SudokuGrid::try_from_string(puzzle) {
+        Ok(sgrid) => sgrid,
+        Err(error) => {
+            record.error = Some(error.to_string());
+            return record;
+        },
+    };
+    let violations = sgrid.validate();
+    if !violations.is_empty() {
+        record.error = Some(violations.iter().map(|violation| violation.to_string()).collect::<Vec<_>>().join(" "));
+        return record;
+    }
+
+    let mut solution = sgrid.clone();
+    if !solution.backtrack_fill() {
+        record.error = Some("The puzzle has no solution.".to_string());
+        return record;
+    }
+    if !sgrid.has_unique_solution() {
+        record.error = Some("The puzzle has more than one solution.".to_string());
+        return record;
+    }
+    record.solution = Some(solution.to_number_string());
+
+    let mut manager = create_manager(sgrid, profile);
+    match manager.solve() {
+        Ok(summary) => record.summary = Some(summary),
+        Err(error) => record.error = Some(error.to_string()),
+    }
+    record
+}
+
+fn parse_profile(options: &HashMap<String, String>) -> Result<Option<Difficulty>, String> {
+    match options.get("profile").map(String::as_str) {
+        None | Some("all") => Ok(None),
+        Some(name) => Ok(Some(Difficulty::from_name(name).ok_or_else(|| format!("Unknown profile \"{}\".", name))?)),
+    }
+}
+
+fn open_input(options: &HashMap<String, String>) -> Result<Box<dyn BufRead>, String> {
+    match options.get("input") {
+        Some(path) => Ok(Box::new(BufReader::new(File::open(path).map_err(|error| format!("Unable to open {}: {}", path, error))?))),
+        None => Ok(Box::new(BufReader::new(io::stdin()))),
+    }
+}
+
+// The puzzle of an input line, blank lines, comments and the header of generated CSV files give None.
+fn puzzle_from_line(line: &str) -> Option<&str> {
+    let puzzle = line.split(',').next().unwrap_or("").trim();
+    if puzzle.is_empty() || puzzle.starts_with('#') || puzzle == "puzzle" { return None; }
+    Some(puzzle)
+}
+
+fn create_manager(sgrid: SudokuGrid, profile: Option<Difficulty>) -> SudokuSolverManager {
+    let mut manager = SudokuSolverManager::new(sgrid);
+    for (tier, solver) in difficulty::default_solvers() {
+        if profile.map_or(true, |profile| tier <= profile) {
+            manager.add_solver(solver);
+        }
+    }
+    manager
+}
+
+fn solve(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let profile = parse_profile(&options)?;
+    let json = match options.get("format").map(String::as_str) {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(other) => return Err(format!("Unknown format \"{}\".", other)),
+    };
+    let input = open_input(&options)?;
+    let mut output = open_output(&options)?;
+
+    let write_error = |error: io::Error| format!("Unable to write output: {}", error);
+    for (index, line) in input.lines().enumerate() {
+        let line = line.map_err(|error| format!("Unable to read input: {}", error))?;
+        let Some(puzzle) = puzzle_from_line(&line) else { continue; };
+
+        let record = solve_puzzle(index + 1, puzzle, profile);
+        if json {
+            writeln!(output, "{}", record.to_json()).map_err(write_error)?;
+        } else {
+            writeln!(output, "{}", record.to_text()).map_err(write_error)?;
+        }
+    }
+    output.flush().map_err(write_error)
+}
+
+fn parse_theme(options: &HashMap<String, String>) -> Result<Theme, String> {
+    match options.get("theme") {
+        Some(name) => Theme::from_name(name).ok_or(format!("Unknown theme \"{}\", expected one of {}.", name, Theme::NAMES.join(", "))),
+        None => Ok(Theme::light()),
+    }
+}
+
+// The puzzle of --puzzle or else the first puzzle of the input, it has to have a unique solution to be stepped through.
+fn read_puzzle(options: &HashMap<String, String>) -> Result<SudokuGrid, String> {
+    let puzzle = match options.get("puzzle") {
+        Some(puzzle) => puzzle.clone(),
+        None => {
+            let mut puzzle = None;
+            for line in open_input(options)?.lines() {
+                let line = line.map_err(|error| format!("Unable to read input: {}", error))?;
+                if let Some(found) = puzzle_from_line(&line) {
+                    puzzle = Some(found.to_string());
+                    break;
+                }
+            }
+            puzzle.ok_or("No puzzle found in the input.")?
+        },
+    };
+
+    let sgrid = SudokuGrid::try_from_string(&puzzle).map_err(|error| error.to_string())?;
+    if !sgrid.has_unique_solution() {
+        return Err("The puzzle does not have a unique solution.".to_string());
+    }
+    Ok(sgrid)
+}
+
+// Prints every step of the solve with the cells and candidates involved highlighted.
+fn explain(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let profile = parse_profile(&options)?;
+    let color = match options.get("color").map(String::as_str) {
+        None | Some("auto") => io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+        Some("always") => true,
+        Some("never") => false,
+        Some(other) => return Err(format!("Unknown color mode \"{}\".", other)),
+    };
+    let theme = parse_theme(&options)?;
+    let sgrid = read_puzzle(&options)?;
+
+    let mut renderer = AnsiRenderer::new(color);
+    renderer.set_theme(theme);
+    let mut manager = create_manager(sgrid, profile);
+    let mut output = BufWriter::new(io::stdout());
+    let write_error = |error: io::Error| format!("Unable to write output: {}", error);
+    loop {
+        let progress = manager.solve_iteration().map_err(|error| error.to_string())?;
+        if !progress { break; }
+        // The first and last visualizers of a step are the boards before and after it
+        let visualizers = manager.visualizers_per_step.last().unwrap();
+        for visualizer in &visualizers[1..visualizers.len() - 1] {
+            writeln!(output, "{}", renderer.render_step(visualizer)).map_err(write_error)?;
+        }
+    }
+
+    let solved = manager.sgrid.clue_count() == 81;
+    let last_board = manager.visualizers_per_step.last().unwrap().last().unwrap();
+    writeln!(output, "{}", if solved { "Solved." } else { "No technique applies, the puzzle is not solved." }).map_err(write_error)?;
+    write!(output, "{}", renderer.render_grid(last_board)).map_err(write_error)?;
+    output.flush().map_err(write_error)
+}
+
+// Writes the image of one visualizer to the path.
+type SaveImage = Box<dyn Fn(&SudokuVisualizerBuilder, &Path) -> io::Result<()>>;
+
+// Writes every stage of every step as a numbered image, the stages of a step are the board before it, one image per
+// instance found and the board after it.
+fn export(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let profile = parse_profile(&options)?;
+    let (width, height) = (parse_size(&options, "width")?, parse_size(&options, "height")?);
+    let theme = parse_theme(&options)?;
+    let (extension, save): (&str, SaveImage) = match options.get("format").map(String::as_str) {
+        None | Some("svg") => {
+            let mut renderer = SvgRenderer::new(width, height);
+            renderer.set_theme(theme);
+            ("svg", Box::new(move |visualizer, path| renderer.save(visualizer, path)))
+        },
+        Some("png") => {
+            let mut renderer = PngRenderer::new(width, height);
+            renderer.set_theme(theme);
+            ("png", Box::new(move |visualizer, path| renderer.save(visualizer, path)))
+        },
+        Some(other) => return Err(format!("Unknown format \"{}\".", other)),
+    };
+    let directory = Path::new(options.get("output").ok_or("Missing --output directory.")?);
+    let sgrid = read_puzzle(&options)?;
+
+    let mut manager = create_manager(sgrid, profile);
+    manager.solve().map_err(|error| error.to_string())?;
+    fs::create_dir_all(directory).map_err(|error| format!("Unable to create {}: {}", directory.display(), error))?;
+    let mut count = 0;
+    for (step, visualizers) in manager.visualizers_per_step.iter().enumerate() {
+        for (stage, visualizer) in visualizers.iter().enumerate() {
+            let path = directory.join(format!("step-{:03}-{:02}.{}", step + 1, stage, extension));
+            save(visualizer, &path).map_err(|error| format!("Unable to write {}: {}", path.display(), error))?;
+            count += 1;
+        }
+    }
+    println!("Wrote {} images to {}.", count, directory.display());
+    Ok(())
+}
+
+// Writes the stages of the solve as the frames of one animated PNG.
+fn animate(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let profile = parse_profile(&options)?;
+    let mut renderer = PngRenderer::new(parse_size(&options, "width")?, parse_size(&options, "height")?);
+    renderer.set_theme(parse_theme(&options)?);
+    let mut exporter = AnimationExporter::new(renderer);
+    exporter.set_frame_delay(Duration::from_millis(parse_number(&options, "delay", 1500)?));
+    exporter.set_final_delay(Duration::from_millis(parse_number(&options, "final-delay", 4000)?));
+    exporter.set_loops(parse_number(&options, "loops", 0)?);
+    match options.get("stages").map(String::as_str) {
+        None | Some("all") => exporter.set_include_boards(true),
+        Some("steps") => exporter.set_include_boards(false),
+        Some(other) => return Err(format!("Unknown stages \"{}\", expected all or steps.", other)),
+    }
+    let path = Path::new(options.get("output").ok_or("Missing --output file.")?);
+    let sgrid = read_puzzle(&options)?;
+
+    let mut manager = create_manager(sgrid, profile);
+    manager.solve().map_err(|error| error.to_string())?;
+    let frames = exporter.frames(&manager.visualizers_per_step).len();
+    exporter.save(&manager.visualizers_per_step, path).map_err(|error| format!("Unable to write {}: {}", path.display(), error))?;
+    println!("Wrote {} frames to {}.", frames, path.display());
+    Ok(())
+}
+
+// Writes the whole solve as a single HTML page for sharing.
+fn report(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let profile = parse_profile(&options)?;
+    let mut html_report = HtmlReport::new();
+    html_report.set_theme(parse_theme(&options)?);
+    let sgrid = read_puzzle(&options)?;
+
+    let mut manager = create_manager(sgrid.clone(), profile);
+    let summary = manager.solve().map_err(|error| error.to_string())?;
+    let mut output = open_output(&options)?;
+    let write_error = |error: io::Error| format!("Unable to write output: {}", error);
+    output.write_all(html_report.render(&sgrid, &manager, &summary).as_bytes()).map_err(write_error)?;
+    output.flush().map_err(write_error)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("generate") => generate(&args[1..]),
+        Some("solve") => solve(&args[1..]),
+        Some("explain") => explain(&args[1..]),
+        Some("export") => export(&args[1..]),
+        Some("animate") => animate(&args[1..]),
+        Some("report") => report(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        },
+    }
+}
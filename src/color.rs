@@ -0,0 +1,27 @@
+// An RGBA colour owned by the crate, so the solvers and visualizers can be used without raylib.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    // Same values as the raylib colours of the same name
+    pub const BLACK: Color = Color::new(0, 0, 0, 255);
+    pub const GRAY: Color = Color::new(130, 130, 130, 255);
+    pub const LIGHTGRAY: Color = Color::new(200, 200, 200, 255);
+    pub const WHITE: Color = Color::new(255, 255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+#[cfg(feature = "gui")]
+impl From<Color> for raylib::prelude::Color {
+    fn from(color: Color) -> Self {
+        raylib::prelude::Color::new(color.r, color.g, color.b, color.a)
+    }
+}
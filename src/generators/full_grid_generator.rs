@@ -0,0 +1,89 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::grid_transform::GridTransform;
+use crate::sudoku_grid::SudokuGrid;
+
+// Fills empty grids with a randomized backtracking search on digit bitmasks. The cell with the
+// fewest options is filled next with its digits in a shuffled order, and a random transformation
+// is applied to the result. This is not exactly uniform but close, unlike the deterministic LCV
+// ordering of SudokuGrid::backtrack_fill.
+pub struct FullGridGenerator;
+
+// Digits used in every row, column and box, bit n is set when digit n is placed.
+struct FillState {
+    grid: [[usize; 9]; 9],
+    rows: [u16; 9],
+    cols: [u16; 9],
+    boxes: [u16; 9],
+}
+
+impl FillState {
+    fn options(&self, row: usize, col: usize) -> u16 {
+        !(self.rows[row] | self.cols[col] | self.boxes[(row / 3) * 3 + col / 3]) & 0b11_1111_1110
+    }
+
+    fn toggle(&mut self, row: usize, col: usize, digit: usize) {
+        self.rows[row] ^= 1 << digit;
+        self.cols[col] ^= 1 << digit;
+        self.boxes[(row / 3) * 3 + col / 3] ^= 1 << digit;
+    }
+
+    fn fill<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        // Most constrained empty cell
+        let mut best: Option<(usize, usize, u16)> = None;
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.grid[row][col] != 0 { continue; }
+                let options = self.options(row, col);
+                if best.map_or(true, |(_, _, best_options)| options.count_ones() < best_options.count_ones()) {
+                    best = Some((row, col, options));
+                    // Nothing beats a dead end or a forced digit
+                    if options.count_ones() <= 1 { break; }
+                }
+            }
+            if best.is_some_and(|(_, _, options)| options.count_ones() <= 1) { break; }
+        }
+        let Some((row, col, options)) = best else { return true; };
+
+        let mut digits = [0; 9];
+        let mut count = 0;
+        for digit in 1..=9 {
+            if options & (1 << digit) != 0 {
+                digits[count] = digit;
+                count += 1;
+            }
+        }
+        digits[..count].shuffle(rng);
+        for &digit in &digits[..count] {
+            self.grid[row][col] = digit;
+            self.toggle(row, col, digit);
+            if self.fill(rng) {
+                return true;
+            }
+            self.toggle(row, col, digit);
+            self.grid[row][col] = 0;
+        }
+        false
+    }
+}
+
+impl FullGridGenerator {
+    pub fn generate_digits<R: Rng + ?Sized>(rng: &mut R) -> [[usize; 9]; 9] {
+        let mut state = FillState {
+            grid: [[0; 9]; 9],
+            rows: [0; 9],
+            cols: [0; 9],
+            boxes: [0; 9],
+        };
+        assert!(state.fill(rng), "An empty grid can always be filled");
+        GridTransform::random(rng).apply_to_digits(&state.grid)
+    }
+
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> SudokuGrid {
+        let mut sgrid = SudokuGrid::new();
+        sgrid.grid = FullGridGenerator::generate_digits(rng);
+        sgrid.regenerate_candidates();
+        sgrid
+    }
+}
@@ -0,0 +1,6 @@
+pub mod symmetry;
+pub mod puzzle_generator;
+pub mod targeted_generator;
+pub mod showcase_generator;
+pub mod full_grid_generator;
+pub mod pattern_generator;
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::sudoku_grid::{GridParseError, SudokuGrid, UnitType};
+
+use super::full_grid_generator::FullGridGenerator;
+use super::puzzle_generator::GeneratedPuzzle;
+
+// Positions of the givens as an 81 bit mask, bit row * 9 + col is set for a clue.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub struct CluePattern(u128);
+
+impl CluePattern {
+    pub fn from_cells(cells: &[(usize, usize)]) -> Result<Self, PatternError> {
+        if let Some(&(row, col)) = cells.iter().find(|&&(row, col)| row >= 9 || col >= 9) {
+            return Err(PatternError::CellOutOfRange(row, col));
+        }
+        Ok(CluePattern(cells.iter().fold(0, |mask, &(row, col)| mask | 1 << (row * 9 + col))))
+    }
+
+    // Reads a pattern drawn with '.' or '0' for empty cells and any other character for a clue, whitespace is ignored.
+    pub fn from_string(pattern: &str) -> Result<Self, GridParseError> {
+        let cells: Vec<char> = pattern.chars().filter(|ch| !ch.is_whitespace()).collect();
+        if cells.len() != 81 {
+            return Err(GridParseError::CellCount(cells.len()));
+        }
+        Ok(CluePattern((0..81).filter(|&index| cells[index] != '.' && cells[index] != '0').fold(0, |mask, index| mask | 1 << index)))
+    }
+
+    pub fn contains(&self, cell: (usize, usize)) -> bool {
+        self.0 & 1 << (cell.0 * 9 + cell.1) != 0
+    }
+
+    pub fn clue_count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    // Keeps the digits of the grid in the clue positions.
+    pub fn apply(&self, grid: &[[usize; 9]; 9]) -> SudokuGrid {
+        let mut sgrid = SudokuGrid::new();
+        sgrid.grid = self.mask(grid);
+        sgrid.regenerate_candidates();
+        sgrid
+    }
+
+    fn mask(&self, grid: &[[usize; 9]; 9]) -> [[usize; 9]; 9] {
+        let mut masked = [[0; 9]; 9];
+        for (row, col) in (0..9).flat_map(|row| (0..9).map(move |col| (row, col))) {
+            if self.contains((row, col)) {
+                masked[row][col] = grid[row][col];
+            }
+        }
+        masked
+    }
+
+    // Two lines of the same band (or stack) without clues can be swapped in any solution, so the puzzle can not be unique.
+    fn interchangeable_lines(&self) -> Option<(UnitType, usize, usize)> {
+        for unit_type in [UnitType::Row, UnitType::Col] {
+            let is_empty = |line: usize| (0..9).all(|i| !self.contains(if unit_type == UnitType::Row { (line, i) } else { (i, line) }));
+            for band in 0..3 {
+                let empty: Vec<usize> = (band * 3..band * 3 + 3).filter(|&line| is_empty(line)).collect();
+                if empty.len() >= 2 {
+                    return Some((unit_type, empty[0], empty[1]));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum PatternError {
+    // A clue cell outside the 9x9 grid.
+    CellOutOfRange(usize, usize),
+    // No sudoku with fewer than 17 clues has a unique solution.
+    TooFewClues(usize),
+    // Two clue free rows or columns of the same band or stack can be swapped.
+    InterchangeableLines(UnitType, usize, usize),
+    // No grid gave a unique puzzle before the time budget ran out.
+    TimeBudgetExceeded { attempts: usize },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatternError::CellOutOfRange(row, col) => write!(f, "The clue cell ({}, {}) is outside the grid.", row, col),
+            PatternError::TooFewClues(count) => write!(f, "The pattern has {} clues, at least 17 are needed for a unique solution.", count),
+            PatternError::InterchangeableLines(unit_type, first, second) => write!(
+                f,
+                "The {} and {} have no clues and can be swapped, the pattern can not give a unique solution.",
+                SudokuGrid::unit_to_str(*unit_type, *first), SudokuGrid::unit_to_str(*unit_type, *second)
+            ),
+            PatternError::TimeBudgetExceeded { attempts } => write!(f, "No unique puzzle found for the pattern in {} attempts.", attempts),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+// Searches random solution grids for one that gives a unique puzzle with givens in exactly the pattern cells.
+pub struct PatternGenerator {
+    pub pattern: CluePattern,
+    pub time_budget: Duration,
+}
+
+impl PatternGenerator {
+    pub fn new(pattern: CluePattern) -> Self {
+        Self {
+            pattern,
+            time_budget: Duration::from_secs(10),
+        }
+    }
+
+    pub fn set_time_budget(&mut self, time_budget: Duration) {
+        self.time_budget = time_budget;
+    }
+
+    // Patterns that can be ruled out without searching.
+    pub fn check_pattern(&self) -> Result<(), PatternError> {
+        if self.pattern.clue_count() < 17 {
+            return Err(PatternError::TooFewClues(self.pattern.clue_count()));
+        }
+        if let Some((unit_type, first, second)) = self.pattern.interchangeable_lines() {
+            return Err(PatternError::InterchangeableLines(unit_type, first, second));
+        }
+        Ok(())
+    }
+
+    pub fn generate(&self) -> Result<GeneratedPuzzle, PatternError> {
+        self.generate_with_seed(rand::thread_rng().gen())
+    }
+
+    // The grids tried only depend on the seed, the time budget decides how many of them are tried.
+    pub fn generate_with_seed(&self, seed: u64) -> Result<GeneratedPuzzle, PatternError> {
+        self.check_pattern()?;
+
+        let start = Instant::now();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut attempts = 0;
+        while start.elapsed() < self.time_budget {
+            attempts += 1;
+            let digits = FullGridGenerator::generate_digits(&mut rng);
+            // The bitmask count is checked before any candidates are built, most grids are rejected here
+            if SudokuGrid::count_solutions(&self.pattern.mask(&digits), 2) == 1 {
+                let puzzle = self.pattern.apply(&digits);
+                let mut solution = SudokuGrid::new();
+                solution.grid = digits;
+                solution.regenerate_candidates();
+                return Ok(GeneratedPuzzle {
+                    puzzle,
+                    solution,
+                    seed,
+                });
+            }
+        }
+        Err(PatternError::TimeBudgetExceeded { attempts })
+    }
+}
@@ -0,0 +1,139 @@
+use rand::{Rng, SeedableRng};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::grid_transform::GridTransform;
+use crate::sudoku_grid::SudokuGrid;
+
+use super::full_grid_generator::FullGridGenerator;
+use super::symmetry::Symmetry;
+
+// A generated puzzle with the data needed to reproduce it.
+#[derive(Clone, Debug)]
+pub struct GeneratedPuzzle {
+    pub puzzle: SudokuGrid,
+    pub solution: SudokuGrid,
+    // Generating with this seed and the same settings gives the same puzzle.
+    pub seed: u64,
+}
+
+impl GeneratedPuzzle {
+    // A disguised copy with the same difficulty. The seed still reproduces the original puzzle.
+    pub fn transformed(&self, transform: &GridTransform) -> GeneratedPuzzle {
+        GeneratedPuzzle {
+            puzzle: transform.apply(&self.puzzle),
+            solution: transform.apply(&self.solution),
+            seed: self.seed,
+        }
+    }
+}
+
+// Generates puzzles with a unique solution by removing clues from a random solution grid.
+pub struct PuzzleGenerator {
+    pub symmetry: Symmetry,
+    // Maximum number of orbits to try removing, every orbit is tried at most once.
+    pub removals: usize,
+    // When enabled every given left after the orbit removals is tried once more on its own,
+    // the result is minimal but single removals can break the symmetry.
+    pub minimal: bool,
+    // Clues are only removed while at least this many are left. Together with minimal, grids are drawn until
+    // one gives a minimal puzzle within the bound, which takes long for bounds far above the usual 20 to 30 clues.
+    pub min_clues: usize,
+}
+
+impl Default for PuzzleGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PuzzleGenerator {
+    pub fn new() -> Self {
+        Self {
+            symmetry: Symmetry::None,
+            removals: 81,
+            minimal: false,
+            min_clues: 0,
+        }
+    }
+
+    pub fn set_symmetry(&mut self, symmetry: Symmetry) {
+        self.symmetry = symmetry;
+    }
+
+    pub fn set_removals(&mut self, removals: usize) {
+        self.removals = removals;
+    }
+
+    pub fn set_minimal(&mut self, minimal: bool) {
+        self.minimal = minimal;
+    }
+
+    pub fn set_min_clues(&mut self, min_clues: usize) {
+        self.min_clues = min_clues;
+    }
+
+    pub fn generate(&self) -> GeneratedPuzzle {
+        self.generate_with_seed(rand::thread_rng().gen())
+    }
+
+    // ChaCha8 gives the same stream on every platform, so a seed reproduces the puzzle anywhere.
+    pub fn generate_with_seed(&self, seed: u64) -> GeneratedPuzzle {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        loop {
+            let solution = FullGridGenerator::generate(&mut rng);
+            let puzzle = self.remove_clues(solution.clone(), &mut rng);
+            // The lower bound can stop the removals before the puzzle is minimal
+            if self.minimal && !puzzle.is_minimal() {
+                continue;
+            }
+            return GeneratedPuzzle {
+                puzzle,
+                solution,
+                seed,
+            };
+        }
+    }
+
+    // Removes whole orbits of clues in a random order, an orbit is put back if the puzzle would no longer be unique.
+    pub fn remove_clues<R: Rng + ?Sized>(&self, mut sgrid: SudokuGrid, rng: &mut R) -> SudokuGrid {
+        let mut orbits = self.symmetry.orbits();
+        orbits.shuffle(rng);
+
+        for orbit in orbits.iter().take(self.removals) {
+            self.try_remove(&mut sgrid, orbit);
+        }
+
+        if self.minimal {
+            let mut givens: Vec<(usize, usize)> = (0..9).flat_map(|row| (0..9).map(move |col| (row, col)))
+                .filter(|&(row, col)| sgrid.grid[row][col] != 0)
+                .collect();
+            givens.shuffle(rng);
+            for given in givens {
+                self.try_remove(&mut sgrid, &[given]);
+            }
+        }
+
+        sgrid
+    }
+
+    // Clears the cells, they are put back if the puzzle would no longer be unique.
+    fn try_remove(&self, sgrid: &mut SudokuGrid, cells: &[(usize, usize)]) {
+        let old_values: Vec<usize> = cells.iter().map(|&(row, col)| sgrid.grid[row][col]).collect();
+        let removed = old_values.iter().filter(|&&value| value != 0).count();
+        if removed == 0 { return; }
+        if sgrid.clue_count() - removed < self.min_clues { return; }
+        let old_candidates = sgrid.candidates.clone();
+
+        for &(row, col) in cells {
+            sgrid.grid[row][col] = 0;
+        }
+        sgrid.regenerate_candidates();
+        if !sgrid.has_unique_solution() {
+            for (&(row, col), &value) in cells.iter().zip(old_values.iter()) {
+                sgrid.grid[row][col] = value;
+            }
+            sgrid.candidates = old_candidates;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::solvers::difficulty;
+use crate::solvers::solver_manager::SudokuSolverManager;
+use crate::solvers::sudoku_solver::SolverError;
+use crate::sudoku_grid::SudokuGrid;
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+
+use super::puzzle_generator::{GeneratedPuzzle, PuzzleGenerator};
+
+// A position where the technique is the next step needed.
+#[derive(Clone)]
+pub struct Showcase {
+    // Digits and pencil marks right before the technique is applied.
+    pub position: SudokuGrid,
+    // Step of the solve in which the technique is applied, counted from 1.
+    pub step: usize,
+    // The visualizer of the technique as the solver manager created it.
+    pub visualizer: SudokuVisualizerBuilder,
+    pub generated: GeneratedPuzzle,
+}
+
+#[derive(Clone, Debug)]
+pub enum ShowcaseError {
+    // The name matches none of the default solvers.
+    UnknownTechnique(String),
+    // None of the generated puzzles needed the technique.
+    NotFound { attempts: usize },
+    Solver(SolverError),
+}
+
+impl fmt::Display for ShowcaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowcaseError::UnknownTechnique(name) => write!(f, "{} is not one of the default solvers.", name),
+            ShowcaseError::NotFound { attempts } => write!(f, "No puzzle needed the technique in {} attempts.", attempts),
+            ShowcaseError::Solver(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ShowcaseError {}
+
+impl From<SolverError> for ShowcaseError {
+    fn from(error: SolverError) -> Self {
+        ShowcaseError::Solver(error)
+    }
+}
+
+// Searches generated puzzles for positions that showcase a technique. Every step before the
+// position is solved with techniques of a lower difficulty.
+pub struct ShowcaseGenerator {
+    // Name of the solver to showcase, as given by SudokuSolveMethod::name.
+    pub technique: &'static str,
+    pub puzzle_generator: PuzzleGenerator,
+    pub max_attempts: usize,
+}
+
+impl ShowcaseGenerator {
+    pub fn new(technique: &'static str) -> Self {
+        Self {
+            technique,
+            puzzle_generator: PuzzleGenerator::new(),
+            max_attempts: 100,
+        }
+    }
+
+    pub fn set_max_attempts(&mut self, max_attempts: usize) {
+        self.max_attempts = max_attempts;
+    }
+
+    // Manager with the default solvers of the tiers below the technique, followed by the technique itself
+    // so it is only used once nothing simpler makes progress.
+    fn create_manager(&self, sgrid: SudokuGrid) -> Result<SudokuSolverManager, ShowcaseError> {
+        let tier = difficulty::difficulty_of(self.technique).ok_or_else(|| ShowcaseError::UnknownTechnique(self.technique.to_string()))?;
+        let mut manager = SudokuSolverManager::new(sgrid);
+        for (solver_tier, solver) in difficulty::default_solvers() {
+            if solver_tier < tier || solver.name() == self.technique {
+                manager.add_solver(solver);
+            }
+        }
+        Ok(manager)
+    }
+
+    // Solves the puzzle with simpler techniques until the technique is needed, returns the position and the step.
+    pub fn find_in(&self, sgrid: &SudokuGrid) -> Result<Option<(SudokuGrid, usize, SudokuVisualizerBuilder)>, ShowcaseError> {
+        let mut manager = self.create_manager(sgrid.clone())?;
+        loop {
+            let position = manager.sgrid.clone();
+            if !manager.solve_iteration()? { return Ok(None); }
+
+            if manager.techniques_per_step.last() == Some(&Some(self.technique)) {
+                let visualizer = manager.visualizers_per_step.last().unwrap()[1].clone();
+                return Ok(Some((position, manager.current_step, visualizer)));
+            }
+        }
+    }
+
+    pub fn generate(&self) -> Result<Showcase, ShowcaseError> {
+        self.generate_with_seed(rand::thread_rng().gen())
+    }
+
+    pub fn generate_with_seed(&self, seed: u64) -> Result<Showcase, ShowcaseError> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        for _ in 0..self.max_attempts {
+            let generated = self.puzzle_generator.generate_with_seed(rng.next_u64());
+            if let Some((position, step, visualizer)) = self.find_in(&generated.puzzle)? {
+                return Ok(Showcase {
+                    position,
+                    step,
+                    visualizer,
+                    generated,
+                });
+            }
+        }
+        Err(ShowcaseError::NotFound { attempts: self.max_attempts })
+    }
+}
@@ -0,0 +1,96 @@
+use crate::sudoku_grid::SudokuGrid;
+
+// Symmetries a clue layout can follow. Clues are removed in orbits so the
+// layout maps onto itself under the symmetry.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub enum Symmetry {
+    None,
+    // Rotating the grid half a turn
+    Rotational180,
+    // Rotating the grid a quarter turn
+    Rotational90,
+    // Reflecting over the top left to bottom right diagonal
+    Diagonal,
+    // Reflecting over the top right to bottom left diagonal
+    AntiDiagonal,
+    // Reflecting top to bottom over the middle row
+    HorizontalMirror,
+    // Reflecting left to right over the middle column
+    VerticalMirror,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 7] = [
+        Symmetry::None,
+        Symmetry::Rotational180,
+        Symmetry::Rotational90,
+        Symmetry::Diagonal,
+        Symmetry::AntiDiagonal,
+        Symmetry::HorizontalMirror,
+        Symmetry::VerticalMirror,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Symmetry::None => "none",
+            Symmetry::Rotational180 => "rotational180",
+            Symmetry::Rotational90 => "rotational90",
+            Symmetry::Diagonal => "diagonal",
+            Symmetry::AntiDiagonal => "antidiagonal",
+            Symmetry::HorizontalMirror => "horizontal",
+            Symmetry::VerticalMirror => "vertical",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Symmetry> {
+        Symmetry::ALL.into_iter().find(|symmetry| symmetry.name() == name.to_lowercase())
+    }
+
+    // Maps a cell to its partner cells under a single application of the symmetry.
+    fn map_cell(&self, cell: (usize, usize)) -> (usize, usize) {
+        let (row, col) = cell;
+        match self {
+            Symmetry::None => (row, col),
+            Symmetry::Rotational180 => (8 - row, 8 - col),
+            Symmetry::Rotational90 => (col, 8 - row),
+            Symmetry::Diagonal => (col, row),
+            Symmetry::AntiDiagonal => (8 - col, 8 - row),
+            Symmetry::HorizontalMirror => (8 - row, col),
+            Symmetry::VerticalMirror => (row, 8 - col),
+        }
+    }
+
+    // All cells that must be given or removed together with the cell, sorted and including the cell itself.
+    pub fn orbit(&self, cell: (usize, usize)) -> Vec<(usize, usize)> {
+        let mut orbit = vec![cell];
+        let mut next = self.map_cell(cell);
+        while next != cell {
+            orbit.push(next);
+            next = self.map_cell(next);
+        }
+        orbit.sort();
+        orbit
+    }
+
+    // Partitions the grid into orbits.
+    pub fn orbits(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut orbits: Vec<Vec<(usize, usize)>> = Vec::new();
+        for row in 0..9 {
+            for col in 0..9 {
+                let orbit = self.orbit((row, col));
+                if orbit[0] == (row, col) {
+                    orbits.push(orbit);
+                }
+            }
+        }
+        orbits
+    }
+
+    // Checks that the givens of the grid form a layout with this symmetry.
+    pub fn matches(&self, sgrid: &SudokuGrid) -> bool {
+        self.orbits().iter().all(|orbit| {
+            let given = sgrid.grid[orbit[0].0][orbit[0].1] != 0;
+            orbit.iter().all(|&(row, col)| (sgrid.grid[row][col] != 0) == given)
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::solvers::difficulty::{self, Difficulty};
+use crate::solvers::solver_manager::{SolveSummary, SudokuSolverManager};
+use crate::sudoku_grid::SudokuGrid;
+
+use super::puzzle_generator::{GeneratedPuzzle, PuzzleGenerator};
+use super::symmetry::Symmetry;
+
+// Generates puzzles until one can be solved with the allowed techniques and needs one of the must use techniques.
+pub struct TargetedGenerator {
+    pub puzzle_generator: PuzzleGenerator,
+    // Names of the solvers the puzzle may be solved with, as given by SudokuSolveMethod::name.
+    pub allowed: Vec<&'static str>,
+    // The puzzle has to need at least one of these techniques, empty means any puzzle is accepted.
+    pub must_use: Vec<&'static str>,
+    pub max_attempts: usize,
+    // Puzzles with more clues are rejected.
+    pub max_clues: usize,
+}
+
+impl Default for TargetedGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TargetedGenerator {
+    pub fn new() -> Self {
+        Self {
+            puzzle_generator: PuzzleGenerator::new(),
+            allowed: difficulty::default_solvers().iter().map(|(_, solver)| solver.name()).collect(),
+            must_use: Vec::new(),
+            max_attempts: 100,
+            max_clues: 81,
+        }
+    }
+
+    // Puzzles that need a technique of the given tier and nothing harder.
+    pub fn for_difficulty(target: Difficulty) -> Self {
+        let solvers = difficulty::default_solvers();
+        let mut generator = Self::new();
+        generator.allowed = solvers.iter().filter(|(tier, _)| *tier <= target).map(|(_, solver)| solver.name()).collect();
+        generator.must_use = solvers.iter().filter(|(tier, _)| *tier == target).map(|(_, solver)| solver.name()).collect();
+        generator
+    }
+
+    // Practice puzzles for a single technique, only techniques of the same tier or easier are allowed besides it.
+    pub fn for_technique(technique: &'static str) -> Self {
+        let target = difficulty::difficulty_of(technique).unwrap_or(Difficulty::Expert);
+        let mut generator = Self::new();
+        generator.allowed = difficulty::default_solvers().iter()
+            .filter(|(tier, solver)| *tier <= target || solver.name() == technique)
+            .map(|(_, solver)| solver.name())
+            .collect();
+        generator.must_use = vec![technique];
+        generator
+    }
+
+    pub fn set_allowed(&mut self, allowed: Vec<&'static str>) {
+        self.allowed = allowed;
+    }
+
+    pub fn set_must_use(&mut self, must_use: Vec<&'static str>) {
+        self.must_use = must_use;
+    }
+
+    pub fn set_max_attempts(&mut self, max_attempts: usize) {
+        self.max_attempts = max_attempts;
+    }
+
+    // Clue counts the puzzles must fall in, the lower bound is enforced while removing clues.
+    pub fn set_clue_range(&mut self, min_clues: usize, max_clues: usize) {
+        self.puzzle_generator.set_min_clues(min_clues);
+        self.max_clues = max_clues;
+    }
+
+    pub fn set_symmetry(&mut self, symmetry: Symmetry) {
+        self.puzzle_generator.set_symmetry(symmetry);
+    }
+
+    // Manager with the allowed solvers in their default order.
+    pub fn create_manager(&self, sgrid: SudokuGrid) -> SudokuSolverManager {
+        let mut manager = SudokuSolverManager::new(sgrid);
+        for (_, solver) in difficulty::default_solvers() {
+            if self.allowed.contains(&solver.name()) {
+                manager.add_solver(solver);
+            }
+        }
+        manager
+    }
+
+    // Solves the puzzle with the allowed techniques, returns the summary if it meets the target.
+    pub fn check(&self, sgrid: &SudokuGrid) -> Option<SolveSummary> {
+        if sgrid.clue_count() > self.max_clues { return None; }
+        let summary = self.create_manager(sgrid.clone()).solve().ok()?;
+        if !summary.solved { return None; }
+        if !self.must_use.is_empty() && !summary.uses_any(&self.must_use) { return None; }
+        Some(summary)
+    }
+
+    pub fn generate(&self) -> Option<(GeneratedPuzzle, SolveSummary)> {
+        self.generate_with_seed(rand::thread_rng().gen())
+    }
+
+    // Each attempt gets its own seed drawn from the given seed, the returned puzzle records the seed of its attempt.
+    pub fn generate_with_seed(&self, seed: u64) -> Option<(GeneratedPuzzle, SolveSummary)> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        for _ in 0..self.max_attempts {
+            let generated = self.puzzle_generator.generate_with_seed(rng.next_u64());
+            if let Some(summary) = self.check(&generated.puzzle) {
+                return Some((generated, summary));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,81 @@
+use crate::color::Color;
+use crate::layout::{ChainPath, GridLayout, Point};
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::Theme;
+
+// The drawing primitives of a backend, the raylib window, the svg and the png renderer all paint through it.
+pub trait Painter {
+    fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color);
+    fn line(&mut self, from: Point, to: Point, thickness: f32, color: Color);
+    // Quadratic Bezier curve, the control point is in the middle.
+    fn curve(&mut self, from: Point, control_point: Point, to: Point, thickness: f32, color: Color);
+    fn text_centered(&mut self, text: &str, center: Point, size: i32, color: Color);
+}
+
+pub fn paint_header<P: Painter>(painter: &mut P, builder: &SudokuVisualizerBuilder, width: i32, height: i32, theme: &Theme) {
+    for line in GridLayout::header_lines(&builder.title, &builder.description, width, height) {
+        painter.text_centered(&line.text, line.center, line.size, theme.text);
+    }
+}
+
+// Paints the grid in the order every backend uses, later parts are drawn on top of earlier ones.
+// Sorted so the output does not depend on the order of the hash maps. Chains bend around the obstacle candidates.
+pub fn paint_grid<P: Painter>(painter: &mut P, layout: &GridLayout, builder: &SudokuVisualizerBuilder, obstacles: &[(usize, usize, usize)], theme: &Theme) {
+    let cell_size = layout.cell_size() as i32;
+    let candidate_text_size = GridLayout::CANDIDATE_TEXT_SIZE;
+
+    let mut cell_highlights: Vec<_> = builder.cell_highlights.iter().collect();
+    cell_highlights.sort();
+    for (&(row, col), &role) in cell_highlights {
+        let corner = layout.cell_corner(row, col);
+        painter.fill_rect(corner.x as i32, corner.y as i32, cell_size, cell_size, theme.fill(role));
+    }
+
+    let mut candidates_highlights: Vec<_> = builder.candidates_highlights.iter().collect();
+    candidates_highlights.sort();
+    for (&(row, col, num), &role) in candidates_highlights {
+        let center = layout.candidate_center(row, col, num);
+        painter.fill_rect(center.x as i32 - candidate_text_size / 2, center.y as i32 - candidate_text_size / 2, candidate_text_size, candidate_text_size, theme.fill(role));
+    }
+
+    for line in GridLayout::LINE_ORDER {
+        let color = if line % 3 == 0 { theme.box_line } else { theme.cell_line };
+        let (horizontal, vertical) = layout.grid_lines(line);
+        painter.line(horizontal.0, horizontal.1, GridLayout::LINE_THICKNESS, color);
+        painter.line(vertical.0, vertical.1, GridLayout::LINE_THICKNESS, color);
+    }
+
+    for i in 0..9 {
+        let row_name = ((b'A' + i as u8) as char).to_string();
+        let col_name = (i + 1).to_string();
+        for (name, point) in [(&row_name, layout.row_labels(i).0), (&row_name, layout.row_labels(i).1), (&col_name, layout.col_labels(i).0), (&col_name, layout.col_labels(i).1)] {
+            painter.text_centered(name, point, GridLayout::LABEL_TEXT_SIZE, theme.text);
+        }
+    }
+
+    let mut digits: Vec<_> = builder.digits.iter().collect();
+    digits.sort();
+    for (&(row, col), &(num, role)) in digits {
+        painter.text_centered(&num.to_string(), layout.cell_center(row, col), GridLayout::DIGIT_TEXT_SIZE, theme.foreground(role));
+    }
+
+    let mut chains: Vec<_> = builder.chains.iter().collect();
+    chains.sort();
+    for (&(from, to), &role) in chains {
+        // Links stored in both directions are drawn once
+        if builder.chains.contains_key(&(to, from)) && (to, from) < (from, to) {
+            continue;
+        }
+        let color = theme.foreground(role);
+        match layout.chain_path(from, to, obstacles.iter().copied()) {
+            ChainPath::Line(a, b) => painter.line(a, b, GridLayout::CHAIN_THICKNESS, color),
+            ChainPath::Curve(a, control_point, b) => painter.curve(a, control_point, b, GridLayout::CHAIN_THICKNESS, color),
+        }
+    }
+
+    let mut candidates: Vec<_> = builder.candidates.iter().collect();
+    candidates.sort();
+    for (&(row, col, num), &role) in candidates {
+        painter.text_centered(&num.to_string(), layout.candidate_center(row, col, num), candidate_text_size, theme.foreground(role));
+    }
+}
@@ -0,0 +1,304 @@
+use itertools::Itertools;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::sudoku_grid::SudokuGrid;
+
+// A transformation that keeps a grid valid. The grid is transposed first, then rows and columns
+// are picked from the source and finally the digits are relabelled.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub struct GridTransform {
+    pub transpose: bool,
+    // Source row for each row of the result, only bands and rows within a band are reordered.
+    pub row_order: [usize; 9],
+    // Source column for each column of the result, only stacks and columns within a stack are reordered.
+    pub col_order: [usize; 9],
+    // New digit for every digit, 0 stays 0 for empty cells.
+    pub digit_map: [usize; 10],
+}
+
+impl GridTransform {
+    pub fn identity() -> Self {
+        Self {
+            transpose: false,
+            row_order: [0, 1, 2, 3, 4, 5, 6, 7, 8],
+            col_order: [0, 1, 2, 3, 4, 5, 6, 7, 8],
+            digit_map: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        }
+    }
+
+    pub fn relabel(digit_map: [usize; 10]) -> Self {
+        Self {
+            digit_map,
+            ..GridTransform::identity()
+        }
+    }
+
+    pub fn permute(row_order: [usize; 9], col_order: [usize; 9]) -> Self {
+        Self {
+            row_order,
+            col_order,
+            ..GridTransform::identity()
+        }
+    }
+
+    pub fn transposed() -> Self {
+        Self {
+            transpose: true,
+            ..GridTransform::identity()
+        }
+    }
+
+    // Rotates the grid clockwise by the number of quarter turns.
+    pub fn rotate(quarter_turns: usize) -> Self {
+        let reversed = [8, 7, 6, 5, 4, 3, 2, 1, 0];
+        let mut transform = GridTransform::identity();
+        match quarter_turns % 4 {
+            1 => {
+                transform.transpose = true;
+                transform.col_order = reversed;
+            },
+            2 => {
+                transform.row_order = reversed;
+                transform.col_order = reversed;
+            },
+            3 => {
+                transform.transpose = true;
+                transform.row_order = reversed;
+            },
+            _ => {},
+        }
+        transform
+    }
+
+    // Any of the 2 * 1296 * 1296 * 9! transformations, each equally likely.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let random_line_order = |rng: &mut R| {
+            let mut bands = [0, 1, 2];
+            bands.shuffle(rng);
+            let mut order = [0; 9];
+            for (i, band) in bands.iter().enumerate() {
+                let mut within = [0, 1, 2];
+                within.shuffle(rng);
+                for j in 0..3 {
+                    order[i * 3 + j] = band * 3 + within[j];
+                }
+            }
+            order
+        };
+
+        let mut digits = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        digits.shuffle(rng);
+        let mut digit_map = [0; 10];
+        digit_map[1..].copy_from_slice(&digits);
+
+        Self {
+            transpose: rng.gen(),
+            row_order: random_line_order(rng),
+            col_order: random_line_order(rng),
+            digit_map,
+        }
+    }
+
+    // Checks that the lines stay in their bands and stacks and the digits are a permutation, so valid grids stay valid.
+    pub fn is_valid(&self) -> bool {
+        let valid_line_order = |order: &[usize; 9]| {
+            order.iter().all_unique() && order.iter().all(|&line| line < 9)
+                && (0..3).all(|band| (0..3).all(|i| order[band * 3 + i] / 3 == order[band * 3] / 3))
+        };
+        valid_line_order(&self.row_order) && valid_line_order(&self.col_order)
+            && self.digit_map[0] == 0 && self.digit_map[1..].iter().all(|&digit| (1..=9).contains(&digit)) && self.digit_map[1..].iter().all_unique()
+    }
+
+    // The transformation that applies this one and then the other.
+    pub fn then(&self, other: &GridTransform) -> GridTransform {
+        let mut combined = GridTransform::identity();
+        combined.transpose = self.transpose != other.transpose;
+        for i in 0..9 {
+            if other.transpose {
+                combined.row_order[i] = self.col_order[other.row_order[i]];
+                combined.col_order[i] = self.row_order[other.col_order[i]];
+            } else {
+                combined.row_order[i] = self.row_order[other.row_order[i]];
+                combined.col_order[i] = self.col_order[other.col_order[i]];
+            }
+        }
+        for digit in 0..10 {
+            combined.digit_map[digit] = other.digit_map[self.digit_map[digit]];
+        }
+        combined
+    }
+
+    // The transformation that undoes this one.
+    pub fn inverse(&self) -> GridTransform {
+        let mut inverse = GridTransform::identity();
+        inverse.transpose = self.transpose;
+        for i in 0..9 {
+            inverse.digit_map[self.digit_map[i + 1]] = i + 1;
+            if self.transpose {
+                inverse.row_order[self.col_order[i]] = i;
+                inverse.col_order[self.row_order[i]] = i;
+            } else {
+                inverse.row_order[self.row_order[i]] = i;
+                inverse.col_order[self.col_order[i]] = i;
+            }
+        }
+        inverse
+    }
+
+    // All 1296 orders of lines that keep every line in a band (or stack) together with the others of its band.
+    pub fn line_orders() -> Vec<[usize; 9]> {
+        let triple_orders: Vec<Vec<usize>> = (0..3).permutations(3).collect();
+        let mut orders = Vec::new();
+        for bands in &triple_orders {
+            for first in &triple_orders {
+                for second in &triple_orders {
+                    for third in &triple_orders {
+                        let mut order = [0; 9];
+                        for (i, within) in [first, second, third].iter().enumerate() {
+                            for j in 0..3 {
+                                order[i * 3 + j] = bands[i] * 3 + within[j];
+                            }
+                        }
+                        orders.push(order);
+                    }
+                }
+            }
+        }
+        orders
+    }
+
+    // The cell of the source grid that ends up in the cell of the result.
+    pub fn source_cell(&self, cell: (usize, usize)) -> (usize, usize) {
+        let (row, col) = (self.row_order[cell.0], self.col_order[cell.1]);
+        if self.transpose { (col, row) } else { (row, col) }
+    }
+
+    pub fn apply_to_digits(&self, grid: &[[usize; 9]; 9]) -> [[usize; 9]; 9] {
+        let mut result = [[0; 9]; 9];
+        for (row, result_row) in result.iter_mut().enumerate() {
+            for (col, digit) in result_row.iter_mut().enumerate() {
+                let (source_row, source_col) = self.source_cell((row, col));
+                *digit = self.digit_map[grid[source_row][source_col]];
+            }
+        }
+        result
+    }
+
+    // Transforms the digits and candidates of the grid.
+    pub fn apply(&self, sgrid: &SudokuGrid) -> SudokuGrid {
+        let mut result = sgrid.clone();
+        result.grid = self.apply_to_digits(&sgrid.grid);
+        for row in 0..9 {
+            for col in 0..9 {
+                let (source_row, source_col) = self.source_cell((row, col));
+                result.candidates[row][col] = sgrid.candidates[source_row][source_col].iter().map(|&digit| self.digit_map[digit]).collect();
+            }
+        }
+        result
+    }
+}
+
+// The minlex form is the smallest 81 digit string, reading empty cells as 0, that any transformation of the grid gives.
+// Returns the canonical grid and the transformation that turns the grid into it.
+// For each of the 2 * 1296 column layouts the rows are placed one by one, and a row that reads larger than the same row
+// of the best string so far cuts off every order starting that way. Most layouts end at the second or third row, so a
+// grid takes a few milliseconds instead of going through all 2 * 1296 * 1296 layouts.
+pub fn canonical_form(sgrid: &SudokuGrid) -> (SudokuGrid, GridTransform) {
+    let mut search = MinlexSearch {
+        source: sgrid.grid,
+        transpose: false,
+        col_order: [0; 9],
+        best: [usize::MAX; 81],
+        best_transform: GridTransform::identity(),
+    };
+
+    for transpose in [false, true] {
+        search.transpose = transpose;
+        if transpose {
+            for (row, col) in (0..9).cartesian_product(0..9) {
+                search.source[row][col] = sgrid.grid[col][row];
+            }
+        }
+        for col_order in GridTransform::line_orders() {
+            search.col_order = col_order;
+            search.place_row(&mut [0; 9], 0, [0; 10], 1, false);
+        }
+    }
+
+    let transform = search.best_transform;
+    (transform.apply(sgrid), transform)
+}
+
+// Depth first search over the row orders of one column layout, pruned against the best string found so far.
+struct MinlexSearch {
+    source: [[usize; 9]; 9],
+    transpose: bool,
+    col_order: [usize; 9],
+    best: [usize; 81],
+    best_transform: GridTransform,
+}
+
+impl MinlexSearch {
+    // Digits are labelled in order of first appearance, which is the smallest labelling for the rows placed so far.
+    // Improved is set once a placed row read smaller than the best string, the rows after it are then open.
+    fn place_row(&mut self, row_order: &mut [usize; 9], position: usize, digit_map: [usize; 10], next_label: usize, improved: bool) {
+        if position == 9 {
+            if improved {
+                // Digits missing from the grid get the remaining labels in order
+                let mut digit_map = digit_map;
+                let mut next_label = next_label;
+                for label in digit_map.iter_mut().skip(1) {
+                    if *label == 0 {
+                        *label = next_label;
+                        next_label += 1;
+                    }
+                }
+                self.best_transform = GridTransform {
+                    transpose: self.transpose,
+                    row_order: *row_order,
+                    col_order: self.col_order,
+                    digit_map,
+                };
+            }
+            return;
+        }
+
+        // The first row of a band can come from any unused band, the others from the rest of its band
+        let band_start = position - position % 3;
+        let choices: Vec<usize> = if position == band_start {
+            (0..9).filter(|&row| row_order[..position].iter().all(|&used| used / 3 != row / 3)).collect()
+        } else {
+            let band = row_order[band_start] / 3;
+            (band * 3..band * 3 + 3).filter(|row| !row_order[band_start..position].contains(row)).collect()
+        };
+
+        for row in choices {
+            let mut row_map = digit_map;
+            let mut row_label = next_label;
+            let mut line = [0; 9];
+            for (col, label) in line.iter_mut().enumerate() {
+                let digit = self.source[row][self.col_order[col]];
+                if digit != 0 && row_map[digit] == 0 {
+                    row_map[digit] = row_label;
+                    row_label += 1;
+                }
+                *label = row_map[digit];
+            }
+
+            let best_line = &mut self.best[position * 9..position * 9 + 9];
+            let row_improved = match line.as_slice().cmp(best_line) {
+                std::cmp::Ordering::Greater => continue,
+                std::cmp::Ordering::Equal => false,
+                std::cmp::Ordering::Less => {
+                    best_line.copy_from_slice(&line);
+                    self.best[position * 9 + 9..].fill(usize::MAX);
+                    true
+                },
+            };
+
+            row_order[position] = row;
+            self.place_row(row_order, position + 1, row_map, row_label, improved || row_improved);
+        }
+    }
+}
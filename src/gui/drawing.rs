@@ -0,0 +1,57 @@
+use raylib::prelude::*;
+
+use crate::color;
+use crate::grid_painter::{paint_grid, paint_header, Painter};
+use crate::layout::{GridLayout, Point};
+use crate::sudoku_grid::SudokuGrid;
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::Theme;
+
+fn vector(point: Point) -> Vector2 {
+    Vector2 { x: point.x, y: point.y }
+}
+
+pub fn draw_text_centered(d: &mut RaylibDrawHandle, text: &str, cell_center_x: i32, cell_center_y: i32, text_size: i32, color: color::Color) {
+    let text_width = measure_text(text, text_size);
+    let text_height = text_size;  // Approximation, should be fine for digits
+    let x = cell_center_x - text_width / 2;
+    let y = cell_center_y - text_height / 2;
+    d.draw_text(text, x, y, text_size, Color::from(color));
+}
+
+struct RaylibPainter<'a, 'b> {
+    draw: &'a mut RaylibDrawHandle<'b>,
+}
+
+impl Painter for RaylibPainter<'_, '_> {
+    fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: color::Color) {
+        self.draw.draw_rectangle(x, y, width, height, Color::from(color));
+    }
+
+    fn line(&mut self, from: Point, to: Point, thickness: f32, color: color::Color) {
+        self.draw.draw_line_ex(vector(from), vector(to), thickness, Color::from(color));
+    }
+
+    fn curve(&mut self, from: Point, control_point: Point, to: Point, thickness: f32, color: color::Color) {
+        self.draw.draw_line_bezier_quad(vector(from), vector(to), vector(control_point), thickness, Color::from(color));
+    }
+
+    fn text_centered(&mut self, text: &str, center: Point, size: i32, color: color::Color) {
+        draw_text_centered(self.draw, text, center.x as i32, center.y as i32, size, color);
+    }
+}
+
+pub fn draw_header(draw: &mut RaylibDrawHandle<'_>, builder: &SudokuVisualizerBuilder, theme: &Theme) {
+    let (width, height) = (draw.get_screen_width(), draw.get_screen_height());
+    paint_header(&mut RaylibPainter { draw }, builder, width, height, theme);
+}
+
+// Chains bend around the unsolved candidates of the grid.
+pub fn draw_sgrid(layout: &GridLayout, draw: &mut RaylibDrawHandle<'_>, builder: &SudokuVisualizerBuilder, sgrid: &SudokuGrid, theme: &Theme) {
+    let unsolved_candidates: Vec<(usize, usize, usize)> = (0..9)
+        .flat_map(|row| (0..9).map(move |col| (row, col)))
+        .filter(|&(row, col)| sgrid.grid[row][col] == 0)
+        .flat_map(|(row, col)| sgrid.candidates[row][col].iter().map(move |&num| (row, col, num)))
+        .collect();
+    paint_grid(&mut RaylibPainter { draw }, layout, builder, &unsolved_candidates, theme);
+}
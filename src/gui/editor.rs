@@ -0,0 +1,89 @@
+use std::path::Path;
+
+use raylib::prelude::*;
+
+use crate::gui::drawing::{draw_header, draw_sgrid};
+use crate::gui::play::key_digit;
+use crate::layout::{GridLayout, Point};
+use crate::puzzle_editor::PuzzleEditor;
+use crate::sudoku_grid::SudokuGrid;
+use crate::theme::Theme;
+
+const HELP: &str = "Click a cell and type the givens, Backspace clears a cell. Ctrl+V pastes a puzzle, drop a file on the window to load it. Enter starts solving, E goes back without changes.";
+
+// Entering a new puzzle in the window.
+pub struct EditorMode {
+    pub editor: PuzzleEditor,
+    pub message: Option<String>,
+}
+
+impl EditorMode {
+    pub fn new(sgrid: &SudokuGrid) -> Self {
+        Self {
+            editor: PuzzleEditor::from_grid(sgrid),
+            message: None,
+        }
+    }
+
+    // Returns the entered puzzle once it is confirmed with Enter and has a unique solution.
+    pub fn handle_input(&mut self, rl: &mut RaylibHandle) -> Option<SudokuGrid> {
+        let layout = GridLayout::below_header(rl.get_screen_width(), rl.get_screen_height());
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_LEFT_BUTTON) {
+            let mouse = rl.get_mouse_position();
+            if let Some((row, col)) = layout.cell_at(Point::new(mouse.x, mouse.y)) {
+                self.editor.select(row, col);
+                self.message = None;
+            }
+        }
+
+        if rl.is_file_dropped() {
+            let files = rl.get_dropped_files();
+            rl.clear_dropped_files();
+            if let Some(file) = files.first() {
+                self.message = Some(match self.editor.load_file(Path::new(file)) {
+                    Ok(()) => format!("Loaded {}.", file),
+                    Err(error) => error.to_string(),
+                });
+            }
+        }
+
+        let control = rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL) || rl.is_key_down(KeyboardKey::KEY_RIGHT_CONTROL);
+        while let Some(key) = rl.get_key_pressed() {
+            self.message = None;
+            match key {
+                KeyboardKey::KEY_UP => self.editor.move_selection(-1, 0),
+                KeyboardKey::KEY_DOWN => self.editor.move_selection(1, 0),
+                KeyboardKey::KEY_LEFT => self.editor.move_selection(0, -1),
+                KeyboardKey::KEY_RIGHT => self.editor.move_selection(0, 1),
+                KeyboardKey::KEY_BACKSPACE | KeyboardKey::KEY_DELETE | KeyboardKey::KEY_ZERO | KeyboardKey::KEY_PERIOD => { self.editor.enter_digit(0); },
+                KeyboardKey::KEY_V if control => {
+                    self.message = Some(match rl.get_clipboard_text() {
+                        Ok(text) => match self.editor.load_string(&text) {
+                            Ok(()) => "Pasted the puzzle from the clipboard.".to_string(),
+                            Err(error) => error.to_string(),
+                        },
+                        Err(_) => "The clipboard does not hold text.".to_string(),
+                    });
+                },
+                KeyboardKey::KEY_ENTER | KeyboardKey::KEY_KP_ENTER => match self.editor.finish() {
+                    Ok(sgrid) => return Some(sgrid),
+                    Err(error) => self.message = Some(error.to_string()),
+                },
+                key => if let Some(digit) = key_digit(key) {
+                    self.editor.enter_digit(digit);
+                },
+            }
+        }
+        None
+    }
+
+    pub fn draw(&self, d: &mut RaylibDrawHandle, theme: &Theme) {
+        let screen_width = d.get_screen_width();
+        let screen_height = d.get_screen_height();
+        let mut builder = self.editor.visualizer();
+        builder.set_description(self.message.as_deref().unwrap_or(HELP));
+
+        draw_header(d, &builder, theme);
+        draw_sgrid(&GridLayout::below_header(screen_width, screen_height), d, &builder, &self.editor.to_grid(), theme);
+    }
+}
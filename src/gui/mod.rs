@@ -0,0 +1,4 @@
+// Raylib drawing of the visualizers, only built with the gui feature.
+pub mod drawing;
+pub mod play;
+pub mod editor;
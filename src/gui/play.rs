@@ -0,0 +1,120 @@
+use raylib::prelude::*;
+
+use crate::gui::drawing::{draw_header, draw_sgrid};
+use crate::layout::{GridLayout, Point};
+use crate::play_session::PlaySession;
+use crate::solvers::sudoku_solver::SolverError;
+use crate::sudoku_grid::SudokuGrid;
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::Theme;
+
+const HELP: &str = "Click a cell and type a digit, Backspace clears it. Shift+digit or N for pencil marks, C fills them in. H for a hint, E to explain the next step, P to go back.";
+
+// Solving a puzzle by hand in the window, the solver manager is only asked when the player wants a hint.
+pub struct PlayMode {
+    pub session: PlaySession,
+    // A hint or explanation shown in place of the board until the next input.
+    pub overlay: Option<SudokuVisualizerBuilder>,
+    pub message: Option<String>,
+    // When set typed digits toggle pencil marks instead of placing digits.
+    pub pencil: bool,
+}
+
+// The digit of the number row and keypad keys.
+pub fn key_digit(key: KeyboardKey) -> Option<usize> {
+    match key {
+        KeyboardKey::KEY_ONE | KeyboardKey::KEY_KP_1 => Some(1),
+        KeyboardKey::KEY_TWO | KeyboardKey::KEY_KP_2 => Some(2),
+        KeyboardKey::KEY_THREE | KeyboardKey::KEY_KP_3 => Some(3),
+        KeyboardKey::KEY_FOUR | KeyboardKey::KEY_KP_4 => Some(4),
+        KeyboardKey::KEY_FIVE | KeyboardKey::KEY_KP_5 => Some(5),
+        KeyboardKey::KEY_SIX | KeyboardKey::KEY_KP_6 => Some(6),
+        KeyboardKey::KEY_SEVEN | KeyboardKey::KEY_KP_7 => Some(7),
+        KeyboardKey::KEY_EIGHT | KeyboardKey::KEY_KP_8 => Some(8),
+        KeyboardKey::KEY_NINE | KeyboardKey::KEY_KP_9 => Some(9),
+        _ => None,
+    }
+}
+
+fn solver_message(error: SolverError) -> String {
+    match error {
+        SolverError::InvalidState(_) => "Resolve the highlighted conflicts first.".to_string(),
+        SolverError::NoSolution => "Some of the entered digits are wrong, the puzzle can not be solved from here.".to_string(),
+        error => error.to_string(),
+    }
+}
+
+impl PlayMode {
+    pub fn new(puzzle: SudokuGrid) -> Self {
+        Self {
+            session: PlaySession::new(puzzle),
+            overlay: None,
+            message: None,
+            pencil: false,
+        }
+    }
+
+    fn show(&mut self, result: Result<Option<SudokuVisualizerBuilder>, SolverError>) {
+        match result {
+            Ok(Some(visualizer)) => self.overlay = Some(visualizer),
+            Ok(None) => self.message = Some("No technique applies to the current board.".to_string()),
+            Err(error) => self.message = Some(solver_message(error)),
+        }
+    }
+
+    pub fn handle_input(&mut self, rl: &mut RaylibHandle) {
+        let layout = GridLayout::below_header(rl.get_screen_width(), rl.get_screen_height());
+        if rl.is_mouse_button_pressed(MouseButton::MOUSE_LEFT_BUTTON) {
+            let mouse = rl.get_mouse_position();
+            if let Some((row, col)) = layout.cell_at(Point::new(mouse.x, mouse.y)) {
+                self.session.select(row, col);
+                self.overlay = None;
+                self.message = None;
+            }
+        }
+
+        let shift = rl.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) || rl.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT);
+        while let Some(key) = rl.get_key_pressed() {
+            self.overlay = None;
+            self.message = None;
+            match key {
+                KeyboardKey::KEY_UP => self.session.move_selection(-1, 0),
+                KeyboardKey::KEY_DOWN => self.session.move_selection(1, 0),
+                KeyboardKey::KEY_LEFT => self.session.move_selection(0, -1),
+                KeyboardKey::KEY_RIGHT => self.session.move_selection(0, 1),
+                KeyboardKey::KEY_BACKSPACE | KeyboardKey::KEY_DELETE | KeyboardKey::KEY_ZERO => { self.session.clear_cell(); },
+                KeyboardKey::KEY_N => self.pencil = !self.pencil,
+                KeyboardKey::KEY_C => self.session.fill_pencil_marks(),
+                KeyboardKey::KEY_H => self.show(self.session.hint()),
+                KeyboardKey::KEY_E => self.show(self.session.explain()),
+                key => if let Some(digit) = key_digit(key) {
+                    if self.pencil || shift {
+                        self.session.toggle_pencil_mark(digit);
+                    } else {
+                        self.session.enter_digit(digit);
+                    }
+                },
+            }
+        }
+    }
+
+    pub fn draw(&self, d: &mut RaylibDrawHandle, theme: &Theme) {
+        let screen_width = d.get_screen_width();
+        let screen_height = d.get_screen_height();
+        let mut builder = self.overlay.clone().unwrap_or_else(|| self.session.visualizer());
+        if self.overlay.is_none() {
+            if self.pencil {
+                builder.set_title("Play - pencil marks");
+            }
+            if builder.description == "-" {
+                builder.set_description(HELP);
+            }
+        }
+        if let Some(message) = &self.message {
+            builder.set_description(message);
+        }
+
+        draw_header(d, &builder, theme);
+        draw_sgrid(&GridLayout::below_header(screen_width, screen_height), d, &builder, &self.session.to_grid(), theme);
+    }
+}
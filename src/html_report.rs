@@ -0,0 +1,114 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::solvers::solver_manager::{SolveSummary, SudokuSolverManager};
+use crate::sudoku_grid::SudokuGrid;
+use crate::svg_renderer::{escape_xml, SvgRenderer};
+use crate::theme::Theme;
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 0 auto; max-width: 900px; padding: 1em; }
+header table { border-collapse: collapse; margin-bottom: 1em; }
+header th, header td { border: 1px solid #bbb; padding: 0.2em 0.6em; text-align: left; font-family: monospace; }
+header th { font-family: sans-serif; }
+nav { display: flex; gap: 1em; align-items: center; margin-bottom: 1em; }
+.stage svg { width: 100%; max-width: 700px; height: auto; }
+.scripted .stage { display: none; }
+.scripted .stage.current { display: block; }
+";
+
+// Shows one stage at a time once the script runs, without scripts every stage is listed below the other.
+const SCRIPT: &str = "(function () {
+  var stages = document.querySelectorAll('.stage');
+  var position = document.getElementById('position');
+  var current = 0;
+  function show(index) {
+    current = Math.max(0, Math.min(stages.length - 1, index));
+    stages.forEach(function (stage, i) { stage.classList.toggle('current', i === current); });
+    position.textContent = 'Stage ' + (current + 1) + ' of ' + stages.length;
+  }
+  document.body.classList.add('scripted');
+  document.getElementById('previous').addEventListener('click', function () { show(current - 1); });
+  document.getElementById('next').addEventListener('click', function () { show(current + 1); });
+  document.addEventListener('keydown', function (event) {
+    if (event.key === 'ArrowLeft') { show(current - 1); }
+    if (event.key === 'ArrowRight') { show(current + 1); }
+  });
+  show(0);
+})();
+";
+
+// Writes a complete solve as one HTML file without external resources, every stage of every step is an inline svg.
+pub struct HtmlReport {
+    pub grid_renderer: SvgRenderer,
+}
+
+impl Default for HtmlReport {
+    fn default() -> Self {
+        HtmlReport::new()
+    }
+}
+
+impl HtmlReport {
+    pub fn new() -> Self {
+        let mut grid_renderer = SvgRenderer::new(700, 700);
+        // Titles and descriptions are part of the page around the grid
+        grid_renderer.set_header(false);
+        Self { grid_renderer }
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.grid_renderer.set_theme(theme);
+    }
+
+    fn header(puzzle: &SudokuGrid, manager: &SudokuSolverManager, summary: &SolveSummary) -> String {
+        let rating = summary.difficulty().map_or("None".to_string(), |difficulty| difficulty.to_string());
+        let solution = if summary.solved { manager.sgrid.to_number_string() } else { "-".to_string() };
+        let rows = [
+            ("Puzzle", puzzle.to_number_string()),
+            ("Clues", puzzle.clue_count().to_string()),
+            ("Solution", solution),
+            ("Solved by techniques", if summary.solved { "yes" } else { "no" }.to_string()),
+            ("Rating", rating),
+            ("Hardest technique", summary.hardest_technique().unwrap_or("None").to_string()),
+            ("Steps", summary.steps.to_string()),
+            ("Techniques", summary.techniques_used.join(", ")),
+        ];
+
+        let mut header = String::from("<header>\n<h1>Sudoku solve report</h1>\n<table>\n");
+        for (name, value) in rows {
+            writeln!(header, "<tr><th>{}</th><td>{}</td></tr>", name, escape_xml(&value)).unwrap();
+        }
+        header.push_str("</table>\n</header>\n");
+        header
+    }
+
+    // The manager should have run the solve the summary describes, its visualizers are the stages of the report.
+    pub fn render(&self, puzzle: &SudokuGrid, manager: &SudokuSolverManager, summary: &SolveSummary) -> String {
+        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        writeln!(html, "<title>Sudoku solve report {}</title>", puzzle.to_number_string()).unwrap();
+        writeln!(html, "<style>\n{}</style>\n</head>\n<body>", STYLE).unwrap();
+        html.push_str(&HtmlReport::header(puzzle, manager, summary));
+        html.push_str("<nav><button id=\"previous\">Previous</button><span id=\"position\"></span><button id=\"next\">Next</button></nav>\n<main>\n");
+
+        for visualizers in &manager.visualizers_per_step {
+            for visualizer in visualizers {
+                html.push_str("<section class=\"stage\">\n");
+                writeln!(html, "<h2>{}</h2>", escape_xml(&visualizer.title)).unwrap();
+                if visualizer.description != "-" {
+                    writeln!(html, "<p>{}</p>", escape_xml(&visualizer.description)).unwrap();
+                }
+                html.push_str(&self.grid_renderer.render(visualizer));
+                html.push_str("</section>\n");
+            }
+        }
+
+        writeln!(html, "</main>\n<script>\n{}</script>\n</body>\n</html>", SCRIPT).unwrap();
+        html
+    }
+
+    pub fn save(&self, puzzle: &SudokuGrid, manager: &SudokuSolverManager, summary: &SolveSummary, path: &Path) -> io::Result<()> {
+        fs::write(path, self.render(puzzle, manager, summary))
+    }
+}
@@ -0,0 +1,227 @@
+// Positions of everything drawn for a visualizer, shared by the raylib window and the file exporters so they all look alike.
+
+use crate::raster::Canvas;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Point {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+// A link between two candidates, bent around candidates that would otherwise lie on the straight line.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum ChainPath {
+    Line(Point, Point),
+    // Quadratic Bezier curve from the first to the last point, the middle point is the control point.
+    Curve(Point, Point, Point),
+}
+
+// A line of the title or description, centered on the point.
+#[derive(PartialEq, Clone, Debug)]
+pub struct TextLine {
+    pub text: String,
+    pub center: Point,
+    pub size: i32,
+}
+
+// The grid is a square in the middle of the canvas, with a border for the row and column names.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct GridLayout {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub size: f32,
+}
+
+impl GridLayout {
+    pub const BORDER_USAGE: f32 = 0.05;
+    pub const LINE_SPACING: f32 = (1.0 - 2.0 * GridLayout::BORDER_USAGE) / 9.0; // 9 segments
+    pub const LINE_THICKNESS: f32 = 2.0;
+    pub const CHAIN_THICKNESS: f32 = 2.0;
+    pub const DIGIT_TEXT_SIZE: i32 = 40;
+    pub const CANDIDATE_TEXT_SIZE: i32 = 10;
+    pub const LABEL_TEXT_SIZE: i32 = 20;
+    // Thin lines are drawn first so the box lines stay on top.
+    pub const LINE_ORDER: [usize; 10] = [1, 2, 4, 5, 7, 8, 0, 3, 6, 9];
+    // Part of the height used for the title and description above the grid.
+    pub const HEADER_SPACING: f32 = 0.15;
+
+    // The largest square that fits the canvas, snapped to whole pixels.
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let size = width.min(height).floor();
+        Self {
+            offset_x: x.floor() + ((width - size) / 2.0).floor(),
+            offset_y: y.floor() + ((height - size) / 2.0).floor(),
+            size,
+        }
+    }
+
+    // The grid below the header of a window or image.
+    pub fn below_header(width: i32, height: i32) -> Self {
+        let header = GridLayout::HEADER_SPACING * height as f32;
+        GridLayout::new(0.0, header.floor(), width as f32, (height as f32 - header).floor())
+    }
+
+    // Title and description lines of the header, wrapped to the width.
+    pub fn header_lines(title: &str, description: &str, width: i32, height: i32) -> Vec<TextLine> {
+        let header = GridLayout::HEADER_SPACING * height as f32;
+        let title_text_size = ((0.24 * header) as i32).max(1);
+        let description_text_size = ((0.13 * header) as i32).max(1);
+
+        let mut lines = Vec::new();
+        let mut y_position = title_text_size / 2;
+        for line in wrap_text(title, width, title_text_size) {
+            lines.push(TextLine { text: line, center: Point::new((width / 2) as f32, y_position as f32), size: title_text_size });
+            y_position += description_text_size;
+        }
+        y_position += description_text_size / 2;
+        for line in wrap_text(description, width, description_text_size) {
+            lines.push(TextLine { text: line, center: Point::new((width / 2) as f32, y_position as f32), size: description_text_size });
+            y_position += description_text_size;
+        }
+        lines
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        GridLayout::LINE_SPACING * self.size
+    }
+
+    pub fn cell_center(&self, row: usize, col: usize) -> Point {
+        Point::new(
+            self.offset_x + (col as f32 + 1.0) * self.cell_size(),
+            self.offset_y + (row as f32 + 1.0) * self.cell_size(),
+        )
+    }
+
+    // Candidates sit in a 3x3 pattern around the cell center.
+    pub fn candidate_center(&self, row: usize, col: usize, num: usize) -> Point {
+        let x_offset = ((num as i32 - 1) % 3 - 1) as f32 * self.cell_size() / 4.0; // 4.0 is a random number that looks nice in the grid.
+        let y_offset = ((num as i32 - 1) / 3 - 1) as f32 * self.cell_size() / 4.0;
+        let center = self.cell_center(row, col);
+        Point::new(center.x + x_offset, center.y + y_offset)
+    }
+
+    // Top left corner of the background of a cell, the side is the cell size.
+    pub fn cell_corner(&self, row: usize, col: usize) -> Point {
+        let center = self.cell_center(row, col);
+        Point::new(
+            center.x - self.cell_size() / 2.0 + GridLayout::LINE_THICKNESS / 2.0,
+            center.y - self.cell_size() / 2.0 + GridLayout::LINE_THICKNESS / 2.0,
+        )
+    }
+
+    // The cell under a point, such as the mouse position, None outside of the grid lines.
+    pub fn cell_at(&self, point: Point) -> Option<(usize, usize)> {
+        let start = GridLayout::BORDER_USAGE * self.size;
+        let col = ((point.x - self.offset_x - start) / self.cell_size()).floor();
+        let row = ((point.y - self.offset_y - start) / self.cell_size()).floor();
+        if (0.0..9.0).contains(&row) && (0.0..9.0).contains(&col) {
+            Some((row as usize, col as usize))
+        } else {
+            None
+        }
+    }
+
+    // Start and end of the horizontal and the vertical grid line with the index, lines 0, 3, 6 and 9 border the boxes.
+    pub fn grid_lines(&self, line: usize) -> ((Point, Point), (Point, Point)) {
+        let start = GridLayout::BORDER_USAGE * self.size;
+        let end = (1.0 - GridLayout::BORDER_USAGE) * self.size;
+        let offset = start + line as f32 * self.cell_size();
+        (
+            (Point::new(self.offset_x + start, self.offset_y + offset), Point::new(self.offset_x + end, self.offset_y + offset)),
+            (Point::new(self.offset_x + offset, self.offset_y + start), Point::new(self.offset_x + offset, self.offset_y + end)),
+        )
+    }
+
+    // Centers of the row name on the left and the right of the grid.
+    pub fn row_labels(&self, row: usize) -> (Point, Point) {
+        let border = GridLayout::BORDER_USAGE * self.size;
+        let y = self.cell_center(row, 0).y;
+        (Point::new(self.offset_x + border / 2.0, y), Point::new(self.offset_x + self.size - border / 2.0, y))
+    }
+
+    // Centers of the column number above and below the grid.
+    pub fn col_labels(&self, col: usize) -> (Point, Point) {
+        let border = GridLayout::BORDER_USAGE * self.size;
+        let x = self.cell_center(0, col).x;
+        (Point::new(x, self.offset_y + border / 2.0), Point::new(x, self.offset_y + self.size - border / 2.0))
+    }
+
+    // A straight line unless one of the other candidates lies on it, then the link bends to keep it readable.
+    pub fn chain_path<I>(&self, from: (usize, usize, usize), to: (usize, usize, usize), candidates: I) -> ChainPath
+    where
+        I: IntoIterator<Item = (usize, usize, usize)>,
+    {
+        let a = self.candidate_center(from.0, from.1, from.2);
+        let b = self.candidate_center(to.0, to.1, to.2);
+
+        fn is_point_on_line_segment(a: &Point, b: &Point, point: &Point) -> bool {
+            let cross_product = (point.y - a.y) * (b.x - a.x) - (point.x - a.x) * (b.y - a.y);
+            if cross_product.abs() > f32::EPSILON {
+                return false;
+            }
+
+            let dot_product = (point.x - a.x) * (b.x - a.x) + (point.y - a.y) * (b.y - a.y);
+            if dot_product < 0.0 {
+                return false;
+            }
+
+            let squared_length = (b.x - a.x) * (b.x - a.x) + (b.y - a.y) * (b.y - a.y);
+            dot_product <= squared_length
+        }
+
+        let intersects = candidates.into_iter().any(|candidate| {
+            candidate != from && candidate != to && is_point_on_line_segment(&a, &b, &self.candidate_center(candidate.0, candidate.1, candidate.2))
+        });
+        if !intersects {
+            return ChainPath::Line(a, b);
+        }
+
+        let middle = Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0);
+        let perp = Point::new(-(a.y - b.y), a.x - b.x);
+        let length = (perp.x * perp.x + perp.y * perp.y).sqrt();
+        let distance = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+        let offset = 0.07 * self.size * (distance / self.size).min(1.0);
+        let control_point = Point::new(middle.x + perp.x / length * offset, middle.y + perp.y / length * offset);
+        ChainPath::Curve(a, control_point, b)
+    }
+}
+
+// Splits the text into lines no wider than max_width pixels in the raster font, breaking between words.
+// A word that is wider on its own is broken between characters.
+pub fn wrap_text(text: &str, max_width: i32, size: i32) -> Vec<String> {
+    let fits = |line: &str| Canvas::measure_text(line, size) <= max_width;
+    let mut lines = Vec::new();
+    let mut current_line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if current_line.is_empty() { word.to_string() } else { format!("{} {}", current_line, word) };
+        if fits(&candidate) {
+            current_line = candidate;
+            continue;
+        }
+        if !current_line.is_empty() {
+            lines.push(current_line);
+        }
+        current_line = String::new();
+        for ch in word.chars() {
+            current_line.push(ch);
+            if !fits(&current_line) && current_line.chars().count() > 1 {
+                current_line.pop();
+                lines.push(current_line);
+                current_line = ch.to_string();
+            }
+        }
+    }
+
+    if !current_line.is_empty() {
+        lines.push(current_line);
+    }
+
+    lines
+}
@@ -1,4 +1,21 @@
+pub mod color;
+pub mod theme;
 pub mod sudoku_grid;
+pub mod grid_transform;
 pub mod adjacency_graph;
 pub mod sudoku_visualizer_builder;
-pub mod solvers;
\ No newline at end of file
+pub mod layout;
+pub mod grid_painter;
+pub mod svg_renderer;
+pub mod png;
+pub mod raster;
+pub mod png_renderer;
+pub mod animation_exporter;
+pub mod html_report;
+pub mod ansi_renderer;
+pub mod solvers;
+pub mod generators;
+pub mod play_session;
+pub mod puzzle_editor;
+#[cfg(feature = "gui")]
+pub mod gui;
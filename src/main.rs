@@ -1,239 +1,37 @@
-use std::collections::HashSet;
 use std::hint::black_box;
 
 use raylib::prelude::*;
 use sudoku_generator::solvers::solver_manager::SudokuSolverManager;
-use sudoku_generator::solvers::x_chain_solver::XChainSolver;
-use sudoku_generator::solvers::xy_chain_solver::XYChainSolver;
+use sudoku_generator::solvers::difficulty::default_solvers;
+use sudoku_generator::solvers::solver_statistics::SolverStatistics;
 use sudoku_generator::sudoku_grid::*;
-use sudoku_generator::solvers::single_candidate_solver::SingleCandidateSolver;
-use sudoku_generator::solvers::naked_singles_solver::HiddenSinglesSolver;
-use sudoku_generator::solvers::naked_candidates_solver::NakedCandidatesSolver;
-use sudoku_generator::solvers::hidden_candidates_solver::HiddenCandidatesSolver;
-use sudoku_generator::solvers::intersection_removal_solver::IntersectionRemovalSolver;
-use sudoku_generator::solvers::x_wing_solver::XWingSolver;
-use sudoku_generator::solvers::singles_chains_solver::SinglesChainsSolver;
-use sudoku_generator::solvers::y_wing_solver::YWingSolver;
-use sudoku_generator::solvers::swordfish_solver::SwordfishSolver;
-use sudoku_generator::solvers::xyz_wing_solver::XYZWingSolver;
-use sudoku_generator::solvers::medusa_3d_solver::Medusa3DSolver;
-use sudoku_generator::solvers::bowmans_bingo_solver::BowmansBingoSolver;
-use sudoku_generator::sudoku_visualizer_builder::SudokuVisualizerBuilder;
-
-fn wrap_text(text: &str, max_char_width: usize) -> Vec<String> {
-    let words = text.split_whitespace();
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-
-    for word in words {
-        if current_line.len() + word.len() > max_char_width {
-            lines.push(current_line.trim().to_string());
-            current_line.clear();
-        }
-        current_line.push_str(word);
-        current_line.push(' ');
-    }
-
-    if !current_line.is_empty() {
-        lines.push(current_line.trim().to_string());
-    }
-
-    lines
+use sudoku_generator::gui::drawing::{draw_header, draw_sgrid};
+use sudoku_generator::gui::editor::EditorMode;
+use sudoku_generator::gui::play::PlayMode;
+use sudoku_generator::layout::GridLayout;
+use sudoku_generator::theme::Theme;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+enum Mode {
+    // Stepping through the solver with SPACE.
+    Viewer,
+    Play,
+    Editor,
 }
 
-fn draw_text_centered(d: &mut RaylibDrawHandle, text: &str, cell_center_x: i32, cell_center_y: i32, text_size: i32, color: Color) {
-    let text_width = measure_text(text, text_size);
-    let text_height = text_size;  // Approximation, should be fine for digits
-    let x = cell_center_x - text_width / 2;
-    let y = cell_center_y - text_height / 2;
-    d.draw_text(text, x, y, text_size, color);
-}
-
-
-fn draw_sgrid(canvas_offset_x: i32, canvas_offset_y: i32, canvas_width: i32, canvas_height: i32, draw: &mut RaylibDrawHandle<'_>, builder: &SudokuVisualizerBuilder, sgrid: &SudokuGrid) {
-    let size = std::cmp::min(canvas_width, canvas_height) as f32;
-    let offset_x = canvas_offset_x + ((canvas_width as f32 - size) / 2.0) as i32;
-    let offset_y = canvas_offset_y + ((canvas_height as f32 - size) / 2.0) as i32;
-
-    const BORDER_USAGE: f32 = 0.05;
-    const LINE_SPACING: f32 = (1.0 - 2.0 * BORDER_USAGE) / 9.0; // 9 segments
-    let line_thickness: f32 = 2.0;
-    let chain_thickness: f32 = 2.0;
-    let candidate_text_size = 10;
-
-    let get_xy_for_candidate = |row: usize, col: usize, num: usize| -> (f32, f32) {
-        let x_offset = ((num as i32 - 1) % 3 - 1) as f32 * LINE_SPACING * size / 4.0; // 4.0 is a random number that looks nice in the grid.
-        let y_offset = ((num as i32 - 1) / 3 - 1) as f32 * LINE_SPACING * size / 4.0;
-        
-        let cell_center_x = offset_x as f32 + (col as f32 + 1.0) * LINE_SPACING * size + x_offset;
-        let cell_center_y = offset_y as f32 + (row as f32 + 1.0) * LINE_SPACING * size + y_offset;
-    
-        (cell_center_x, cell_center_y)
-    };
-
-    // Highlights - Cell
-    for (&(row, col), &color) in &builder.cell_highlights {
-        let x = offset_x as f32 + (col + 1) as f32 * LINE_SPACING * size - LINE_SPACING * size / 2.0 + line_thickness / 2.0;
-        let y = offset_y as f32 + (row + 1) as f32 * LINE_SPACING * size - LINE_SPACING * size / 2.0 + line_thickness / 2.0;
-        draw.draw_rectangle(x as i32, y as i32, (LINE_SPACING * size) as i32, (LINE_SPACING * size) as i32, color);
-    }
-
-    // Highlights - Digit
-    for (&(row, col, num), &color) in &builder.candidates_highlights {
-        let (x, y) = get_xy_for_candidate(row, col, num);
-
-        draw.draw_rectangle(x as i32 - candidate_text_size / 2, y as i32 - candidate_text_size / 2, candidate_text_size, candidate_text_size, color);
-    }
-
-    for line in [1,2,4,5,7,8,0,3,6,9] { 
-        let color = if line % 3 == 0 {
-            Color::BLACK
-        } else {
-            Color::LIGHTGRAY
-        };
-
-        let offset = BORDER_USAGE * size + line as f32 * LINE_SPACING * size;
-
-        // Draw horizontal lines
-        draw.draw_line_ex(
-            Vector2 { x: (BORDER_USAGE * size + offset_x as f32), y: (offset + offset_y as f32) }, 
-            Vector2 { x: ((1.0 - BORDER_USAGE) * size + offset_x as f32), y: (offset + offset_y as f32) }, 
-            line_thickness,
-            color
-        );
-
-        // Draw vertical lines
-        draw.draw_line_ex(
-            Vector2 { x: (offset + offset_x as f32), y: (BORDER_USAGE * size + offset_y as f32) },
-            Vector2 { x: (offset + offset_x as f32), y: ((1.0 - BORDER_USAGE) * size + offset_y as f32) },
-            line_thickness,
-            color
-        );
-    }
-
-    // Draw row and column names
-    for (i, name) in ('A'..='I').enumerate() {
-        let x_left = offset_x as f32 + (BORDER_USAGE * size) / 2.0;
-        let x_right = offset_x as f32 + (1.0 - BORDER_USAGE) * size + (BORDER_USAGE * size) / 2.0;
-        let y = offset_y as f32 + (i as f32 + 1.0) * LINE_SPACING * size;
-        draw_text_centered(draw, &name.to_string(), x_left as i32, y as i32, 20, Color::BLACK);
-        draw_text_centered(draw, &name.to_string(), x_right as i32, y as i32, 20, Color::BLACK);
-    }
-
-
-    for i in 1..=9 {
-        let x = offset_x as f32 + i as f32 * LINE_SPACING * size;
-        let y_top = offset_y as f32 + (BORDER_USAGE * size) / 2.0;
-        let y_bot = offset_y as f32 + (1.0 - BORDER_USAGE) * size + (BORDER_USAGE * size) / 2.0;
-        draw_text_centered(draw, &i.to_string(), x as i32, y_top as i32, 20, Color::BLACK);
-        draw_text_centered(draw, &i.to_string(), x as i32, y_bot as i32, 20, Color::BLACK);
-    }
-
-    // Draw digits
-    for (&(row, col), &(num, color)) in &builder.digits {
-        let cell_center_x = offset_x as f32 + (col as f32 + 1.0) * LINE_SPACING * size;
-        let cell_center_y = offset_y as f32 + (row as f32 + 1.0) * LINE_SPACING * size;
-        draw_text_centered(draw, &num.to_string(), cell_center_x as i32, cell_center_y as i32, 40, color);
-    }
-
-    let mut drawn_pairs: HashSet<((usize, usize, usize), (usize, usize, usize))> = Default::default();
-    for (&((row_from, col_from, num_from), (row_to, col_to, num_to)), &color) in &builder.chains {
-        let current_pair = ((row_from, col_from, num_from), (row_to, col_to, num_to));
-        let reversed_pair = ((row_to, col_to, num_to), (row_from, col_from, num_from));
-    
-        if drawn_pairs.contains(&reversed_pair) {
-            continue;
-        }
-    
-        drawn_pairs.insert(current_pair);
-    
-        let a = {
-            let (x, y) = get_xy_for_candidate(row_from, col_from, num_from);
-            Vector2 { x, y }
-        };
-    
-        let b = {
-            let (x, y) = get_xy_for_candidate(row_to, col_to, num_to);
-            Vector2 { x, y }
-        };
-    
-        fn is_point_on_line_segment(a: &Vector2, b: &Vector2, point: &Vector2) -> bool {
-            let cross_product = (point.y - a.y) * (b.x - a.x) - (point.x - a.x) * (b.y - a.y);
-            if cross_product.abs() > f32::EPSILON {
-                return false;
-            }
-        
-            let dot_product = (point.x - a.x) * (b.x - a.x) + (point.y - a.y) * (b.y - a.y);
-            if dot_product < 0.0 {
-                return false;
-            }
-        
-            let squared_length = (b.x - a.x) * (b.x - a.x) + (b.y - a.y) * (b.y - a.y);
-            if dot_product > squared_length {
-                return false;
-            }
-        
+// Starts stepping through a new grid, returns whether the solver is already stuck.
+fn start_solving(solver: &mut SudokuSolverManager, grid: SudokuGrid) -> bool {
+    println!("Sudoku id: {}", grid.to_number_string());
+    solver.new_grid(grid);
+    match solver.solve_iteration() {
+        Ok(_) => false,
+        Err(error) => {
+            println!("Solver error: {}", error);
             true
-        }
-
-        let intersects = (0..9).flat_map(|row| {
-            (0..9).flat_map(move |col| {
-                sgrid.candidates[row][col].iter().filter_map(move |&num| {
-                    if sgrid.grid[row][col] != 0 {
-                        return None;
-                    }
-                    let point = {
-                        let (x, y) = get_xy_for_candidate(row, col, num);
-                        Vector2 { x, y }
-                    };
-    
-                    if (row, col, num) != (row_from, col_from, num_from) && (row, col, num) != (row_to, col_to, num_to) && is_point_on_line_segment(&a, &b, &point) {
-                        Some(point)
-                    } else {
-                        None
-                    }
-                })
-            })
-        }).next().is_some();
-
-        if !intersects {
-            draw.draw_line_ex(a, b, chain_thickness, color);
-            continue;
-        }
-    
-        let m = (a + b) / 2.0;       
-    
-        let perp = Vector2 {
-            x: - (a.y - b.y),
-            y: a.x - b.x,
-        };
-    
-        let length = perp.length();
-        let perp_normalized = Vector2 {
-            x: perp.x / length,
-            y: perp.y / length,
-        };
-    
-        let distance = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
-        let offset = 0.07 * size * (distance / size).min(1.0);
-    
-        let control_point = Vector2 {
-            x: m.x + perp_normalized.x * offset,
-            y: m.y + perp_normalized.y * offset,
-        };
-    
-        draw.draw_line_bezier_quad(a, b, control_point, chain_thickness, color);
-    }
-
-    // Draw candidates
-    for (&(row, col, num), &color) in &builder.candidates {
-        let (cell_center_x, cell_center_y) = get_xy_for_candidate(row, col, num);
-        
-        draw_text_centered(draw, &num.to_string(), cell_center_x as i32, cell_center_y as i32, candidate_text_size, color);
+        },
     }
 }
 
-
 fn main() {
     #[cfg(feature = "profiling")]
     const PROFILING: bool = true;
@@ -264,7 +62,8 @@ fn main() {
     let very_hard = SudokuGrid::from_string("800005007030000060207000080000000000000070419083006000401200000002068001000140250");
     let very_hard_2 = SudokuGrid::from_string("900875001000109000001302009190023058208057103530081060400706800000204000700518004");
 
-    let grid = simple_col_4.clone();
+    // The puzzle shown at start, others are entered in the editor
+    let mut grid = simple_col_4.clone();
 
     let puzzles = vec![
         hidden_single, hidden_triple, simplest_sudoku,
@@ -282,85 +81,94 @@ fn main() {
     
     
     let mut solver: SudokuSolverManager = SudokuSolverManager::new(grid.clone());
-    println!("Sudoku id: {}", grid.to_number_string());
 
-    solver.add_solver(Box::new(SingleCandidateSolver));
-    solver.add_solver(Box::new(HiddenSinglesSolver));
-    solver.add_solver(Box::new(NakedCandidatesSolver::<2>));
-    solver.add_solver(Box::new(NakedCandidatesSolver::<3>));
-    solver.add_solver(Box::new(HiddenCandidatesSolver::<2>));
-    solver.add_solver(Box::new(HiddenCandidatesSolver::<3>));
-    solver.add_solver(Box::new(NakedCandidatesSolver::<4>));
-    solver.add_solver(Box::new(HiddenCandidatesSolver::<4>));
-    solver.add_solver(Box::new(IntersectionRemovalSolver));
-    solver.add_solver(Box::new(XWingSolver));
-    solver.add_solver(Box::new(YWingSolver));
-    solver.add_solver(Box::new(XChainSolver));
-    solver.add_solver(Box::new(SinglesChainsSolver));
-    solver.add_solver(Box::new(XYChainSolver));
-    solver.add_solver(Box::new(SwordfishSolver::<3>));
-    solver.add_solver(Box::new(XYZWingSolver));
-    solver.add_solver(Box::new(SwordfishSolver::<4>));
-    solver.add_solver(Box::new(Medusa3DSolver));
-    solver.add_solver(Box::new(BowmansBingoSolver));
+    for (_, technique) in default_solvers() {
+        solver.add_solver(technique);
+    }
 
     let mut iter = 0;
-    let mut done = false;
 
     if PROFILING {
+        solver.set_collect_statistics(true);
+        // New grids start with fresh statistics, the totals are summed over all puzzles
+        let mut totals = solver.statistics.clone();
         for puzzle in puzzles {
             solver.new_grid(puzzle);
-            while solver.solve_iteration() { black_box(()); }
+            while solver.solve_iteration().expect("Profiling puzzles should be solvable") { black_box(()); }
+            totals.iter_mut().zip(&solver.statistics).for_each(|(total, stats)| total.merge(stats));
         }
+        println!("{}", SolverStatistics::report(&totals));
     } else {
-        const HEADER_SPACING: f32 = 0.15;
+        let theme = Theme::light();
 
         let (mut rl, thread) = raylib::init()
             .size(800, 800)
             .title("Sudoku Visualizer")
             .resizable()
             .build();
-        solver.solve_iteration();
+        let mut done = start_solving(&mut solver, grid.clone());
+        println!("SPACE steps through the solve, P solves the puzzle by hand and E opens the editor.");
+
+        let mut play = PlayMode::new(grid.clone());
+        let mut editor = EditorMode::new(&grid);
+        let mut mode = Mode::Viewer;
         while !rl.window_should_close() {
+            if mode != Mode::Play && rl.is_key_pressed(KeyboardKey::KEY_E) {
+                // The editor starts from the current puzzle each time it is opened
+                if mode == Mode::Viewer {
+                    editor = EditorMode::new(&grid);
+                }
+                mode = if mode == Mode::Editor { Mode::Viewer } else { Mode::Editor };
+            } else if mode != Mode::Editor && rl.is_key_pressed(KeyboardKey::KEY_P) {
+                mode = if mode == Mode::Play { Mode::Viewer } else { Mode::Play };
+            }
+
+            match mode {
+                Mode::Play => {
+                    play.handle_input(&mut rl);
+                    let mut d = rl.begin_drawing(&thread);
+                    d.clear_background(Color::from(theme.background));
+                    play.draw(&mut d, &theme);
+                    continue;
+                },
+                Mode::Editor => {
+                    if let Some(puzzle) = editor.handle_input(&mut rl) {
+                        grid = puzzle;
+                        done = start_solving(&mut solver, grid.clone());
+                        iter = 0;
+                        play = PlayMode::new(grid.clone());
+                        mode = Mode::Viewer;
+                    }
+                    let mut d = rl.begin_drawing(&thread);
+                    d.clear_background(Color::from(theme.background));
+                    editor.draw(&mut d, &theme);
+                    continue;
+                },
+                Mode::Viewer => {},
+            }
+
             let builder = &solver.visualizers_per_step.last().unwrap()[iter];
             let mut d = rl.begin_drawing(&thread);
-            d.clear_background(Color::WHITE);
+            d.clear_background(Color::from(theme.background));
 
             let screen_width = d.get_screen_width();
             let screen_height = d.get_screen_height();
 
-            let header_pixles = HEADER_SPACING * screen_height as f32;
-            let title_text_size = (0.24 * header_pixles) as i32;
-            let description_text_size = (0.13 * header_pixles) as i32;
-
-            // Draw title
-            let mut y_position = title_text_size / 2;
-            {
-                let wrapped_description = wrap_text(&builder.title, (screen_width / title_text_size * 2) as usize);
-                for line in wrapped_description {
-                    draw_text_centered(&mut d, &line, screen_width / 2, y_position, title_text_size, Color::BLACK);
-                    y_position += description_text_size as i32;
-                }
-            }
-
-            // Draw description
-            {
-                let wrapped_description = wrap_text(&builder.description, (screen_width / description_text_size * 2) as usize);
-                y_position += description_text_size / 2;
-                for line in wrapped_description {
-                    draw_text_centered(&mut d, &line, screen_width / 2, y_position, description_text_size, Color::BLACK);
-                    y_position += description_text_size as i32;
-                }
-            }
-
+            // Draw title and description
+            draw_header(&mut d, builder, &theme);
 
-            draw_sgrid(0,header_pixles as i32, screen_width, (screen_height as f32 - header_pixles) as i32, &mut d, builder, &solver.sgrid);
+            draw_sgrid(&GridLayout::below_header(screen_width, screen_height), &mut d, builder, &solver.sgrid, &theme);
 
             if d.is_key_pressed(KeyboardKey::KEY_SPACE) && !done {
-                if iter == 2 {
+                if iter == solver.visualizers_per_step.last().unwrap().len() - 1 {
                     println!("Running next solver iteration");
-                    if !solver.solve_iteration() {
-                        done = true;
+                    match solver.solve_iteration() {
+                        Ok(true) => {},
+                        Ok(false) => done = true,
+                        Err(error) => {
+                            println!("Solver error: {}", error);
+                            done = true;
+                        },
                     }
                     iter = 0;
                 } else {
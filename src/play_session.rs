@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use crate::solvers::difficulty::default_solvers;
+use crate::solvers::solver_manager::SudokuSolverManager;
+use crate::solvers::sudoku_solver::SolverError;
+use crate::sudoku_grid::{GridViolation, SudokuGrid};
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::HighlightRole;
+
+// A puzzle being solved by hand: the givens, the digits and pencil marks entered by the player and the selected cell.
+#[derive(Clone, Debug)]
+pub struct PlaySession {
+    pub puzzle: SudokuGrid,
+    pub entries: [[usize; 9]; 9],
+    pub pencil_marks: [[HashSet<usize>; 9]; 9],
+    pub selected: Option<(usize, usize)>,
+}
+
+impl PlaySession {
+    pub fn new(puzzle: SudokuGrid) -> Self {
+        Self {
+            puzzle,
+            entries: [[0; 9]; 9],
+            pencil_marks: Default::default(),
+            selected: None,
+        }
+    }
+
+    pub fn is_given(&self, row: usize, col: usize) -> bool {
+        self.puzzle.grid[row][col] != 0
+    }
+
+    // The given or entered digit of the cell, 0 when it is empty.
+    pub fn digit(&self, row: usize, col: usize) -> usize {
+        if self.is_given(row, col) { self.puzzle.grid[row][col] } else { self.entries[row][col] }
+    }
+
+    pub fn select(&mut self, row: usize, col: usize) {
+        self.selected = Some((row, col));
+    }
+
+    // Moves the selection by the offset, wrapping around the edges of the grid. Starts in the top left corner.
+    pub fn move_selection(&mut self, row_offset: isize, col_offset: isize) {
+        self.selected = Some(match self.selected {
+            Some((row, col)) => ((row as isize + row_offset).rem_euclid(9) as usize, (col as isize + col_offset).rem_euclid(9) as usize),
+            None => (0, 0),
+        });
+    }
+
+    // Places the digit in the selected cell, givens can not be changed. Returns whether the grid changed.
+    pub fn enter_digit(&mut self, digit: usize) -> bool {
+        match self.selected {
+            Some((row, col)) if !self.is_given(row, col) && self.entries[row][col] != digit => {
+                self.entries[row][col] = digit;
+                self.pencil_marks[row][col].clear();
+                true
+            },
+            _ => false,
+        }
+    }
+
+    // Removes the digit or else the pencil marks of the selected cell.
+    pub fn clear_cell(&mut self) -> bool {
+        match self.selected {
+            Some((row, col)) if self.entries[row][col] != 0 => {
+                self.entries[row][col] = 0;
+                true
+            },
+            Some((row, col)) if !self.pencil_marks[row][col].is_empty() => {
+                self.pencil_marks[row][col].clear();
+                true
+            },
+            _ => false,
+        }
+    }
+
+    // Pencil marks can only be made in empty cells.
+    pub fn toggle_pencil_mark(&mut self, digit: usize) -> bool {
+        match self.selected {
+            Some((row, col)) if self.digit(row, col) == 0 => {
+                if !self.pencil_marks[row][col].remove(&digit) {
+                    self.pencil_marks[row][col].insert(digit);
+                }
+                true
+            },
+            _ => false,
+        }
+    }
+
+    // Replaces the pencil marks of every empty cell with the digits not yet placed in its row, column or box.
+    pub fn fill_pencil_marks(&mut self) {
+        let sgrid = self.to_grid();
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.digit(row, col) == 0 {
+                    self.pencil_marks[row][col] = sgrid.candidates[row][col].clone();
+                }
+            }
+        }
+    }
+
+    // The givens and entered digits with the candidates they leave, pencil marks are the player's notes and are not used.
+    pub fn to_grid(&self) -> SudokuGrid {
+        let mut sgrid = SudokuGrid::new();
+        for row in 0..9 {
+            for col in 0..9 {
+                sgrid.grid[row][col] = self.digit(row, col);
+            }
+        }
+        sgrid.regenerate_candidates();
+        sgrid
+    }
+
+    // Cells with a digit placed twice in a unit, or an empty cell every digit is already placed around.
+    pub fn conflicts(&self) -> HashSet<(usize, usize)> {
+        let mut conflicts = HashSet::new();
+        for violation in self.to_grid().validate() {
+            match violation {
+                GridViolation::DuplicateDigit { cells, .. } => conflicts.extend(cells),
+                GridViolation::EmptyCell(row, col) => { conflicts.insert((row, col)); },
+                GridViolation::DigitMissingFromUnit { .. } => {},
+            }
+        }
+        conflicts
+    }
+
+    pub fn is_solved(&self) -> bool {
+        (0..9).all(|row| (0..9).all(|col| self.digit(row, col) != 0)) && self.conflicts().is_empty()
+    }
+
+    // The board as the player sees it, with the selected cell and conflicts highlighted.
+    pub fn visualizer(&self) -> SudokuVisualizerBuilder {
+        let mut visualizer = SudokuVisualizerBuilder::new();
+        let conflicts = self.conflicts();
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.is_given(row, col) {
+                    visualizer.color_digit(row, col, self.puzzle.grid[row][col], HighlightRole::Given);
+                } else if self.entries[row][col] != 0 {
+                    let role = if conflicts.contains(&(row, col)) { HighlightRole::Conflict } else { HighlightRole::Solved };
+                    visualizer.color_digit(row, col, self.entries[row][col], role);
+                } else {
+                    for &digit in &self.pencil_marks[row][col] {
+                        visualizer.color_candidate(row, col, digit, HighlightRole::Candidate);
+                    }
+                }
+            }
+        }
+        for &(row, col) in &conflicts {
+            visualizer.color_cell(row, col, HighlightRole::Conflict);
+        }
+        if let Some((row, col)) = self.selected {
+            visualizer.color_cell(row, col, HighlightRole::Selected);
+        }
+
+        visualizer.set_title("Play");
+        if self.is_solved() {
+            visualizer.set_description("Solved!");
+        }
+        visualizer
+    }
+
+    // Runs the solver manager on the current board, the entered digits have to agree with the solution.
+    fn next_step(&self) -> Result<Option<(&'static str, SudokuVisualizerBuilder)>, SolverError> {
+        let sgrid = self.to_grid();
+        if !self.conflicts().is_empty() {
+            return Err(SolverError::InvalidState(sgrid.validate()));
+        }
+        // Wrong digits can leave a digit without a place in a unit before any cell conflicts
+        if !sgrid.has_unique_solution() {
+            return Err(SolverError::NoSolution);
+        }
+
+        let mut manager = SudokuSolverManager::new(sgrid);
+        for (_, technique) in default_solvers() {
+            manager.add_solver(technique);
+        }
+        if !manager.solve_iteration()? {
+            return Ok(None);
+        }
+        let technique = manager.techniques_per_step.last().copied().flatten().unwrap();
+        // The first visualizer of a step is the board before it
+        Ok(manager.visualizers_per_step.pop().unwrap().into_iter().nth(1).map(|visualizer| (technique, visualizer)))
+    }
+
+    // Names the technique that makes progress next and highlights the cells it uses, without giving the deduction away.
+    pub fn hint(&self) -> Result<Option<SudokuVisualizerBuilder>, SolverError> {
+        Ok(self.next_step()?.map(|(technique, step)| {
+            let mut hint = self.visualizer();
+            for (&(row, col), &role) in &step.cell_highlights {
+                if role != HighlightRole::Elimination {
+                    hint.color_cell(row, col, HighlightRole::Pivot);
+                }
+            }
+            hint.set_title("Hint");
+            hint.set_description(&format!("Look for a {} in the highlighted cells.", technique));
+            hint
+        }))
+    }
+
+    // The full visualizer of the next step, as shown when stepping through a solve.
+    pub fn explain(&self) -> Result<Option<SudokuVisualizerBuilder>, SolverError> {
+        Ok(self.next_step()?.map(|(_, step)| step))
+    }
+}
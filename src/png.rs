@@ -0,0 +1,243 @@
+// A small PNG encoder for RGB images, with its own zlib stream so exporting images needs no extra dependencies.
+
+pub const SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+const LENGTH_BASE: [usize; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u32; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [usize; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+const DISTANCE_EXTRA: [u32; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+
+const WINDOW_SIZE: usize = 32768;
+const MAX_MATCH: usize = 258;
+const MAX_CHAIN: usize = 32;
+const HASH_BITS: u32 = 15;
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut table = [0u32; 256];
+    for (n, entry) in table.iter_mut().enumerate() {
+        let mut c = n as u32;
+        for _ in 0..8 {
+            c = if c & 1 != 0 { 0xEDB88320 ^ (c >> 1) } else { c >> 1 };
+        }
+        *entry = c;
+    }
+    !data.iter().fold(0xFFFFFFFF, |crc, &byte| table[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 bytes is the most that can be summed before the 32 bit sums could overflow
+    for block in data.chunks(5552) {
+        for &byte in block {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+// Writes bits starting at the least significant bit of each byte, as deflate expects.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write_bits(&mut self, value: u32, count: u32) {
+        self.buffer |= value << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    // Huffman codes are stored starting at their most significant bit.
+    fn write_code(&mut self, code: u32, length: u32) {
+        self.write_bits(code.reverse_bits() >> (32 - length), length);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+// The code of a literal or length symbol in the fixed Huffman table.
+fn write_symbol(writer: &mut BitWriter, symbol: usize) {
+    let symbol = symbol as u32;
+    match symbol {
+        0..=143 => writer.write_code(0x30 + symbol, 8),
+        144..=255 => writer.write_code(0x190 + symbol - 144, 9),
+        256..=279 => writer.write_code(symbol - 256, 7),
+        _ => writer.write_code(0xC0 + symbol - 280, 8),
+    }
+}
+
+fn write_match(writer: &mut BitWriter, length: usize, distance: usize) {
+    let code = LENGTH_BASE.iter().rposition(|&base| base <= length).unwrap();
+    write_symbol(writer, 257 + code);
+    writer.write_bits((length - LENGTH_BASE[code]) as u32, LENGTH_EXTRA[code]);
+
+    let code = DISTANCE_BASE.iter().rposition(|&base| base <= distance).unwrap();
+    writer.write_code(code as u32, 5);
+    writer.write_bits((distance - DISTANCE_BASE[code]) as u32, DISTANCE_EXTRA[code]);
+}
+
+fn hash(data: &[u8], position: usize) -> usize {
+    let value = (data[position] as u32) << 16 | (data[position + 1] as u32) << 8 | data[position + 2] as u32;
+    (value.wrapping_mul(2654435761) >> (32 - HASH_BITS)) as usize
+}
+
+// A single deflate block with the fixed Huffman codes, matches are found through hash chains of the last 32 KiB.
+pub fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter { bytes: Vec::new(), buffer: 0, count: 0 };
+    // Final block, fixed Huffman codes
+    writer.write_bits(1, 1);
+    writer.write_bits(1, 2);
+
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut previous = vec![usize::MAX; data.len()];
+    let insert = |head: &mut [usize], previous: &mut [usize], position: usize| {
+        if position + 3 <= data.len() {
+            let key = hash(data, position);
+            previous[position] = head[key];
+            head[key] = position;
+        }
+    };
+
+    let mut position = 0;
+    while position < data.len() {
+        let mut best_length = 0;
+        let mut best_distance = 0;
+        if position + 3 <= data.len() {
+            let max_length = MAX_MATCH.min(data.len() - position);
+            let mut candidate = head[hash(data, position)];
+            let mut chain = 0;
+            while candidate != usize::MAX && position - candidate <= WINDOW_SIZE && chain < MAX_CHAIN {
+                let length = (0..max_length).take_while(|&i| data[candidate + i] == data[position + i]).count();
+                if length > best_length {
+                    best_length = length;
+                    best_distance = position - candidate;
+                    if length == max_length { break; }
+                }
+                candidate = previous[candidate];
+                chain += 1;
+            }
+        }
+
+        if best_length >= 3 {
+            write_match(&mut writer, best_length, best_distance);
+            for offset in 0..best_length {
+                insert(&mut head, &mut previous, position + offset);
+            }
+            position += best_length;
+        } else {
+            write_symbol(&mut writer, data[position] as usize);
+            insert(&mut head, &mut previous, position);
+            position += 1;
+        }
+    }
+    write_symbol(&mut writer, 256);
+    writer.finish()
+}
+
+pub fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32 KiB window, the check bits make the header a multiple of 31
+    let mut stream = vec![0x78, 0x01];
+    stream.extend(deflate(data));
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+pub fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+pub fn header_chunk(png: &mut Vec<u8>, width: usize, height: usize) {
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bit RGB, deflate, standard filter method, no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+    write_chunk(png, b"IHDR", &header);
+}
+
+// The compressed image data, every row is prefixed with filter type 0 since flat areas compress well without filtering.
+pub fn image_data(width: usize, height: usize, rgb: &[u8]) -> Vec<u8> {
+    let mut scanlines = Vec::with_capacity(height * (width * 3 + 1));
+    for row in rgb.chunks(width * 3) {
+        scanlines.push(0);
+        scanlines.extend(row);
+    }
+    zlib_compress(&scanlines)
+}
+
+// Encodes 8 bit RGB pixels, row by row from the top left.
+pub fn encode_png(width: usize, height: usize, rgb: &[u8]) -> Vec<u8> {
+    assert_eq!(rgb.len(), width * height * 3, "Pixel data does not match the image size.");
+    let mut png = SIGNATURE.to_vec();
+    header_chunk(&mut png, width, height);
+    write_chunk(&mut png, b"IDAT", &image_data(width, height, rgb));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+// One image of an animation and how long it is shown.
+pub struct Frame {
+    pub rgb: Vec<u8>,
+    pub delay_ms: u16,
+}
+
+// Encodes the frames as an animated PNG, viewers without APNG support show the first frame. A loop count of 0 repeats forever.
+pub fn encode_apng(width: usize, height: usize, frames: &[Frame], loops: u32) -> Vec<u8> {
+    assert!(!frames.is_empty(), "An animation needs at least one frame.");
+    let mut png = SIGNATURE.to_vec();
+    header_chunk(&mut png, width, height);
+
+    let mut animation_control = Vec::new();
+    animation_control.extend((frames.len() as u32).to_be_bytes());
+    animation_control.extend(loops.to_be_bytes());
+    write_chunk(&mut png, b"acTL", &animation_control);
+
+    // Frame control and frame data chunks share one sequence
+    let mut sequence = 0u32;
+    for (index, frame) in frames.iter().enumerate() {
+        assert_eq!(frame.rgb.len(), width * height * 3, "Pixel data does not match the image size.");
+        let mut frame_control = Vec::new();
+        frame_control.extend(sequence.to_be_bytes());
+        frame_control.extend((width as u32).to_be_bytes());
+        frame_control.extend((height as u32).to_be_bytes());
+        frame_control.extend(0u32.to_be_bytes());
+        frame_control.extend(0u32.to_be_bytes());
+        frame_control.extend(frame.delay_ms.to_be_bytes());
+        frame_control.extend(1000u16.to_be_bytes());
+        // Every frame replaces the whole image
+        frame_control.extend([0, 0]);
+        write_chunk(&mut png, b"fcTL", &frame_control);
+        sequence += 1;
+
+        let data = image_data(width, height, &frame.rgb);
+        if index == 0 {
+            write_chunk(&mut png, b"IDAT", &data);
+        } else {
+            let mut frame_data = sequence.to_be_bytes().to_vec();
+            frame_data.extend(data);
+            write_chunk(&mut png, b"fdAT", &frame_data);
+            sequence += 1;
+        }
+    }
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::grid_painter::{paint_grid, paint_header};
+use crate::layout::GridLayout;
+use crate::png;
+use crate::raster::Canvas;
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::Theme;
+
+// Rasterizes visualizers in software with the layout of the raylib window, so images can be made without a window or GPU.
+pub struct PngRenderer {
+    pub width: i32,
+    pub height: i32,
+    pub theme: Theme,
+    // Without the header only the grid is drawn, filling the whole image.
+    pub header: bool,
+}
+
+impl PngRenderer {
+    // Panics unless both sizes are at least one pixel.
+    pub fn new(width: i32, height: i32) -> Self {
+        assert!(width > 0 && height > 0, "Image size must be at least 1x1, got {}x{}.", width, height);
+        Self {
+            width,
+            height,
+            theme: Theme::light(),
+            header: true,
+        }
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn set_header(&mut self, header: bool) {
+        self.header = header;
+    }
+
+    pub fn layout(&self) -> GridLayout {
+        if self.header {
+            GridLayout::below_header(self.width, self.height)
+        } else {
+            GridLayout::new(0.0, 0.0, self.width as f32, self.height as f32)
+        }
+    }
+
+    // Pixels of the image, sorted so they do not depend on the order of the hash maps.
+    pub fn render_canvas(&self, builder: &SudokuVisualizerBuilder) -> Canvas {
+        let mut canvas = Canvas::new(self.width as usize, self.height as usize, self.theme.background);
+        if self.header {
+            paint_header(&mut canvas, builder, self.width, self.height, &self.theme);
+        }
+        let obstacles: Vec<(usize, usize, usize)> = builder.candidates.keys().copied().collect();
+        paint_grid(&mut canvas, &self.layout(), builder, &obstacles, &self.theme);
+        canvas
+    }
+
+    pub fn render(&self, builder: &SudokuVisualizerBuilder) -> Vec<u8> {
+        let canvas = self.render_canvas(builder);
+        png::encode_png(canvas.width, canvas.height, &canvas.pixels)
+    }
+
+    pub fn save(&self, builder: &SudokuVisualizerBuilder, path: &Path) -> io::Result<()> {
+        fs::write(path, self.render(builder))
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::sudoku_grid::{GridParseError, GridViolation, SudokuGrid};
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::HighlightRole;
+
+// A reason the entered puzzle can not be used.
+#[derive(Debug)]
+pub enum EditorError {
+    Parse(GridParseError),
+    Read(PathBuf, io::Error),
+    // A digit is placed more than once in a unit.
+    Conflict,
+    // The givens allow no solution or more than one.
+    NotUnique,
+}
+
+impl fmt::Display for EditorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EditorError::Parse(error) => write!(f, "Unable to read the puzzle. {}", error),
+            EditorError::Read(path, error) => write!(f, "Unable to read {}: {}", path.display(), error),
+            EditorError::Conflict => write!(f, "Some digits are placed twice in a row, column or box."),
+            EditorError::NotUnique => write!(f, "The puzzle does not have a unique solution."),
+        }
+    }
+}
+
+impl std::error::Error for EditorError {}
+
+impl From<GridParseError> for EditorError {
+    fn from(error: GridParseError) -> Self {
+        EditorError::Parse(error)
+    }
+}
+
+// The givens of a puzzle being entered, the editor of the visualizer works on it.
+#[derive(PartialEq, Clone, Debug)]
+pub struct PuzzleEditor {
+    pub grid: [[usize; 9]; 9],
+    pub selected: Option<(usize, usize)>,
+}
+
+impl Default for PuzzleEditor {
+    fn default() -> Self {
+        PuzzleEditor::new()
+    }
+}
+
+impl PuzzleEditor {
+    pub fn new() -> Self {
+        Self {
+            grid: [[0; 9]; 9],
+            selected: None,
+        }
+    }
+
+    pub fn from_grid(sgrid: &SudokuGrid) -> Self {
+        Self {
+            grid: sgrid.grid,
+            selected: None,
+        }
+    }
+
+    pub fn select(&mut self, row: usize, col: usize) {
+        self.selected = Some((row, col));
+    }
+
+    // Moves the selection by the offset, wrapping around the edges of the grid. Starts in the top left corner.
+    pub fn move_selection(&mut self, row_offset: isize, col_offset: isize) {
+        self.selected = Some(match self.selected {
+            Some((row, col)) => ((row as isize + row_offset).rem_euclid(9) as usize, (col as isize + col_offset).rem_euclid(9) as usize),
+            None => (0, 0),
+        });
+    }
+
+    // Sets the given of the selected cell, 0 clears it.
+    pub fn enter_digit(&mut self, digit: usize) -> bool {
+        match self.selected {
+            Some((row, col)) if self.grid[row][col] != digit => {
+                self.grid[row][col] = digit;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.grid = [[0; 9]; 9];
+    }
+
+    // Reads a puzzle from pasted or loaded text: 81 cells, whitespace and line breaks between them are ignored.
+    // Other text is read like an input file of the command line tool, the first field of the first puzzle line.
+    pub fn parse_puzzle(text: &str) -> Result<SudokuGrid, GridParseError> {
+        let compact: String = text.chars().filter(|ch| !ch.is_whitespace()).collect();
+        if compact.chars().count() == 81 {
+            return SudokuGrid::try_from_string(&compact);
+        }
+        let puzzle = text.lines()
+            .map(|line| line.split(',').next().unwrap_or("").trim())
+            .find(|field| !field.is_empty() && !field.starts_with('#') && *field != "puzzle")
+            .unwrap_or("");
+        SudokuGrid::try_from_string(puzzle)
+    }
+
+    // Replaces the givens, the grid is left unchanged when the text is not a puzzle.
+    pub fn load_string(&mut self, text: &str) -> Result<(), EditorError> {
+        self.grid = PuzzleEditor::parse_puzzle(text)?.grid;
+        Ok(())
+    }
+
+    pub fn load_file(&mut self, path: &Path) -> Result<(), EditorError> {
+        let text = fs::read_to_string(path).map_err(|error| EditorError::Read(path.to_path_buf(), error))?;
+        self.load_string(&text)
+    }
+
+    pub fn to_grid(&self) -> SudokuGrid {
+        let mut sgrid = SudokuGrid::new();
+        sgrid.grid = self.grid;
+        sgrid.regenerate_candidates();
+        sgrid
+    }
+
+    // Cells holding a digit that is placed more than once in one of their units.
+    pub fn conflicts(&self) -> HashSet<(usize, usize)> {
+        self.to_grid().validate().into_iter().flat_map(|violation| match violation {
+            GridViolation::DuplicateDigit { cells, .. } => cells,
+            _ => Vec::new(),
+        }).collect()
+    }
+
+    // The entered puzzle, if it is one that can be stepped through.
+    pub fn finish(&self) -> Result<SudokuGrid, EditorError> {
+        if !self.conflicts().is_empty() {
+            return Err(EditorError::Conflict);
+        }
+        let sgrid = self.to_grid();
+        if !sgrid.has_unique_solution() {
+            return Err(EditorError::NotUnique);
+        }
+        Ok(sgrid)
+    }
+
+    pub fn visualizer(&self) -> SudokuVisualizerBuilder {
+        let mut visualizer = SudokuVisualizerBuilder::new();
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.grid[row][col] != 0 {
+                    visualizer.color_digit(row, col, self.grid[row][col], HighlightRole::Given);
+                }
+            }
+        }
+        for (row, col) in self.conflicts() {
+            visualizer.color_cell(row, col, HighlightRole::Conflict);
+            visualizer.color_digit(row, col, self.grid[row][col], HighlightRole::Conflict);
+        }
+        if let Some((row, col)) = self.selected {
+            visualizer.color_cell(row, col, HighlightRole::Selected);
+        }
+
+        let clues = self.grid.iter().flatten().filter(|&&digit| digit != 0).count();
+        visualizer.set_title(&format!("Editor - {} clues", clues));
+        visualizer
+    }
+}
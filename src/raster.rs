@@ -0,0 +1,164 @@
+use crate::color::Color;
+use crate::grid_painter::Painter;
+use crate::layout::Point;
+
+// Columns of the 5x7 glyphs of the printable ASCII characters, bit 0 is the top row.
+const FONT: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x5F, 0x00, 0x00], [0x00, 0x07, 0x00, 0x07, 0x00], [0x14, 0x7F, 0x14, 0x7F, 0x14],
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], [0x23, 0x13, 0x08, 0x64, 0x62], [0x36, 0x49, 0x56, 0x20, 0x50], [0x00, 0x05, 0x03, 0x00, 0x00],
+    [0x00, 0x1C, 0x22, 0x41, 0x00], [0x00, 0x41, 0x22, 0x1C, 0x00], [0x14, 0x08, 0x3E, 0x08, 0x14], [0x08, 0x08, 0x3E, 0x08, 0x08],
+    [0x00, 0x50, 0x30, 0x00, 0x00], [0x08, 0x08, 0x08, 0x08, 0x08], [0x00, 0x60, 0x60, 0x00, 0x00], [0x20, 0x10, 0x08, 0x04, 0x02],
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], [0x00, 0x42, 0x7F, 0x40, 0x00], [0x42, 0x61, 0x51, 0x49, 0x46], [0x21, 0x41, 0x45, 0x4B, 0x31],
+    [0x18, 0x14, 0x12, 0x7F, 0x10], [0x27, 0x45, 0x45, 0x45, 0x39], [0x3C, 0x4A, 0x49, 0x49, 0x30], [0x01, 0x71, 0x09, 0x05, 0x03],
+    [0x36, 0x49, 0x49, 0x49, 0x36], [0x06, 0x49, 0x49, 0x29, 0x1E], [0x00, 0x36, 0x36, 0x00, 0x00], [0x00, 0x56, 0x36, 0x00, 0x00],
+    [0x08, 0x14, 0x22, 0x41, 0x00], [0x14, 0x14, 0x14, 0x14, 0x14], [0x00, 0x41, 0x22, 0x14, 0x08], [0x02, 0x01, 0x51, 0x09, 0x06],
+    [0x32, 0x49, 0x79, 0x41, 0x3E], [0x7E, 0x11, 0x11, 0x11, 0x7E], [0x7F, 0x49, 0x49, 0x49, 0x36], [0x3E, 0x41, 0x41, 0x41, 0x22],
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], [0x7F, 0x49, 0x49, 0x49, 0x41], [0x7F, 0x09, 0x09, 0x09, 0x01], [0x3E, 0x41, 0x49, 0x49, 0x7A],
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], [0x00, 0x41, 0x7F, 0x41, 0x00], [0x20, 0x40, 0x41, 0x3F, 0x01], [0x7F, 0x08, 0x14, 0x22, 0x41],
+    [0x7F, 0x40, 0x40, 0x40, 0x40], [0x7F, 0x02, 0x0C, 0x02, 0x7F], [0x7F, 0x04, 0x08, 0x10, 0x7F], [0x3E, 0x41, 0x41, 0x41, 0x3E],
+    [0x7F, 0x09, 0x09, 0x09, 0x06], [0x3E, 0x41, 0x51, 0x21, 0x5E], [0x7F, 0x09, 0x19, 0x29, 0x46], [0x46, 0x49, 0x49, 0x49, 0x31],
+    [0x01, 0x01, 0x7F, 0x01, 0x01], [0x3F, 0x40, 0x40, 0x40, 0x3F], [0x1F, 0x20, 0x40, 0x20, 0x1F], [0x3F, 0x40, 0x38, 0x40, 0x3F],
+    [0x63, 0x14, 0x08, 0x14, 0x63], [0x07, 0x08, 0x70, 0x08, 0x07], [0x61, 0x51, 0x49, 0x45, 0x43], [0x00, 0x7F, 0x41, 0x41, 0x00],
+    [0x02, 0x04, 0x08, 0x10, 0x20], [0x00, 0x41, 0x41, 0x7F, 0x00], [0x04, 0x02, 0x01, 0x02, 0x04], [0x40, 0x40, 0x40, 0x40, 0x40],
+    [0x00, 0x01, 0x02, 0x04, 0x00], [0x20, 0x54, 0x54, 0x54, 0x78], [0x7F, 0x48, 0x44, 0x44, 0x38], [0x38, 0x44, 0x44, 0x44, 0x20],
+    [0x38, 0x44, 0x44, 0x48, 0x7F], [0x38, 0x54, 0x54, 0x54, 0x18], [0x08, 0x7E, 0x09, 0x01, 0x02], [0x0C, 0x52, 0x52, 0x52, 0x3E],
+    [0x7F, 0x08, 0x04, 0x04, 0x78], [0x00, 0x44, 0x7D, 0x40, 0x00], [0x20, 0x40, 0x44, 0x3D, 0x00], [0x7F, 0x10, 0x28, 0x44, 0x00],
+    [0x00, 0x41, 0x7F, 0x40, 0x00], [0x7C, 0x04, 0x18, 0x04, 0x78], [0x7C, 0x08, 0x04, 0x04, 0x78], [0x38, 0x44, 0x44, 0x44, 0x38],
+    [0x7C, 0x14, 0x14, 0x14, 0x08], [0x08, 0x14, 0x14, 0x18, 0x7C], [0x7C, 0x08, 0x04, 0x04, 0x08], [0x48, 0x54, 0x54, 0x54, 0x20],
+    [0x04, 0x3F, 0x44, 0x40, 0x20], [0x3C, 0x40, 0x40, 0x20, 0x7C], [0x1C, 0x20, 0x40, 0x20, 0x1C], [0x3C, 0x40, 0x30, 0x40, 0x3C],
+    [0x44, 0x28, 0x10, 0x28, 0x44], [0x0C, 0x50, 0x50, 0x50, 0x3C], [0x44, 0x64, 0x54, 0x4C, 0x44], [0x00, 0x08, 0x36, 0x41, 0x00],
+    [0x00, 0x00, 0x7F, 0x00, 0x00], [0x00, 0x41, 0x36, 0x08, 0x00], [0x10, 0x08, 0x08, 0x10, 0x08],
+];
+
+// Pixels of an RGB image that shapes are blended into, used to render images without a window.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    // Three bytes per pixel, row by row from the top left.
+    pub pixels: Vec<u8>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Self {
+            width,
+            height,
+            pixels: [background.r, background.g, background.b].repeat(width * height),
+        }
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Color {
+        let index = (y * self.width + x) * 3;
+        Color::new(self.pixels[index], self.pixels[index + 1], self.pixels[index + 2], 255)
+    }
+
+    // Mixes the colour into the pixel, the coverage is the part of the pixel the shape covers.
+    pub fn blend(&mut self, x: i32, y: i32, color: Color, coverage: f32) {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return;
+        }
+        let alpha = coverage.clamp(0.0, 1.0) * color.a as f32 / 255.0;
+        let index = (y as usize * self.width + x as usize) * 3;
+        for (channel, value) in [color.r, color.g, color.b].into_iter().enumerate() {
+            let old = self.pixels[index + channel] as f32;
+            self.pixels[index + channel] = (old + (value as f32 - old) * alpha).round() as u8;
+        }
+    }
+
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        for py in y..y + height {
+            for px in x..x + width {
+                self.blend(px, py, color, 1.0);
+            }
+        }
+    }
+
+    // An antialiased line through the points, every pixel is blended once so joints of translucent lines stay even.
+    pub fn draw_polyline(&mut self, points: &[Point], thickness: f32, color: Color) {
+        let reach = thickness / 2.0 + 1.0;
+        let min_x = points.iter().map(|point| point.x).fold(f32::MAX, f32::min) - reach;
+        let max_x = points.iter().map(|point| point.x).fold(f32::MIN, f32::max) + reach;
+        let min_y = points.iter().map(|point| point.y).fold(f32::MAX, f32::min) - reach;
+        let max_y = points.iter().map(|point| point.y).fold(f32::MIN, f32::max) + reach;
+
+        let distance_to_segment = |point: Point, a: Point, b: Point| {
+            let (dx, dy) = (b.x - a.x, b.y - a.y);
+            let squared_length = dx * dx + dy * dy;
+            let t = if squared_length == 0.0 { 0.0 } else { (((point.x - a.x) * dx + (point.y - a.y) * dy) / squared_length).clamp(0.0, 1.0) };
+            ((point.x - a.x - t * dx).powi(2) + (point.y - a.y - t * dy).powi(2)).sqrt()
+        };
+
+        for y in min_y.floor() as i32..=max_y.ceil() as i32 {
+            for x in min_x.floor() as i32..=max_x.ceil() as i32 {
+                let center = Point::new(x as f32 + 0.5, y as f32 + 0.5);
+                let distance = points.windows(2).map(|segment| distance_to_segment(center, segment[0], segment[1])).fold(f32::MAX, f32::min);
+                let coverage = thickness / 2.0 + 0.5 - distance;
+                if coverage > 0.0 {
+                    self.blend(x, y, color, coverage);
+                }
+            }
+        }
+    }
+
+    pub fn draw_line(&mut self, from: Point, to: Point, thickness: f32, color: Color) {
+        self.draw_polyline(&[from, to], thickness, color);
+    }
+
+    // A quadratic Bezier curve, flattened into short segments.
+    pub fn draw_quadratic(&mut self, from: Point, control_point: Point, to: Point, thickness: f32, color: Color) {
+        const SEGMENTS: usize = 24;
+        let points: Vec<Point> = (0..=SEGMENTS).map(|i| {
+            let t = i as f32 / SEGMENTS as f32;
+            let (a, b, c) = ((1.0 - t) * (1.0 - t), 2.0 * (1.0 - t) * t, t * t);
+            Point::new(a * from.x + b * control_point.x + c * to.x, a * from.y + b * control_point.y + c * to.y)
+        }).collect();
+        self.draw_polyline(&points, thickness, color);
+    }
+
+    // Glyphs are scaled to the text size like the default raylib font, a size of 10 draws them one pixel per dot.
+    fn text_scale(size: i32) -> i32 {
+        (size / 10).max(1)
+    }
+
+    pub fn measure_text(text: &str, size: i32) -> i32 {
+        let count = text.chars().count() as i32;
+        if count == 0 { 0 } else { (count * 6 - 1) * Canvas::text_scale(size) }
+    }
+
+    // Draws the text with the built in font, characters outside of printable ASCII are drawn as '?'.
+    pub fn draw_text_centered(&mut self, text: &str, center: Point, size: i32, color: Color) {
+        let scale = Canvas::text_scale(size);
+        let mut x = center.x as i32 - Canvas::measure_text(text, size) / 2;
+        let y = center.y as i32 - 7 * scale / 2;
+        for ch in text.chars() {
+            let index = if (' '..='~').contains(&ch) { ch as usize - ' ' as usize } else { '?' as usize - ' ' as usize };
+            for (column, bits) in FONT[index].iter().enumerate() {
+                for row in 0..7 {
+                    if bits & 1 << row != 0 {
+                        self.fill_rect(x + column as i32 * scale, y + row * scale, scale, scale, color);
+                    }
+                }
+            }
+            x += 6 * scale;
+        }
+    }
+}
+
+impl Painter for Canvas {
+    fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        Canvas::fill_rect(self, x, y, width, height, color);
+    }
+
+    fn line(&mut self, from: Point, to: Point, thickness: f32, color: Color) {
+        self.draw_line(from, to, thickness, color);
+    }
+
+    fn curve(&mut self, from: Point, control_point: Point, to: Point, thickness: f32, color: Color) {
+        self.draw_quadratic(from, control_point, to, thickness, color);
+    }
+
+    fn text_centered(&mut self, text: &str, center: Point, size: i32, color: Color) {
+        self.draw_text_centered(text, center, size, color);
+    }
+}
@@ -1,11 +1,10 @@
 use std::collections::HashMap;
 
 use itertools::iproduct;
-use raylib::prelude::Color;
 
-use crate::{sudoku_grid::SudokuGrid, sudoku_visualizer_builder::Colors};
+use crate::{sudoku_grid::SudokuGrid, theme::HighlightRole};
 
-use super::sudoku_solver::{SudokuSolveMethod, SolverResult, SolverAction, VisualizerUpdate};
+use super::sudoku_solver::{SudokuSolveMethod, SolverResult, SolverAction, SolverError, VisualizerUpdate};
 
 #[derive(PartialEq, Debug)]
 enum CandidateState {
@@ -18,7 +17,7 @@ enum CandidateState {
 pub struct BowmansBingoSolver;
 
 impl SudokuSolveMethod for BowmansBingoSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         // Step 1: Filter out any (row, col) pairs where sgrid.grid[row][col] != 0.
         let valid_positions: Vec<_> = iproduct!(0..9, 0..9)
         .filter(|&(row, col)| sgrid.grid[row][col] == 0)
@@ -48,8 +47,8 @@ impl SudokuSolveMethod for BowmansBingoSolver {
             // Place the starting candidate face down
             candidate_states.insert((start_row, start_col, start_num), CandidateState::Forced);
 
-            visualizer_updates.push(VisualizerUpdate::ColorCell(start_row, start_col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
-            visualizer_updates.push(VisualizerUpdate::BackgroundCandidate(start_row, start_col, start_num, Colors::CHAIN_RED));
+            visualizer_updates.push(VisualizerUpdate::ColorCell(start_row, start_col, HighlightRole::Elimination));
+            visualizer_updates.push(VisualizerUpdate::BackgroundCandidate(start_row, start_col, start_num, HighlightRole::ChainOn));
 
             // Main loop for Bowman's Bingo
             loop {
@@ -71,7 +70,7 @@ impl SudokuSolveMethod for BowmansBingoSolver {
                         if (seen_row, seen_col) == (row, col) { continue; }
                         if candidate_states.contains_key(&(seen_row, seen_col, num)) {
                             candidate_states.insert((seen_row, seen_col, num), CandidateState::Disabled);
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(seen_row, seen_col, num, Color::new(25,25,25,100)));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(seen_row, seen_col, num, HighlightRole::Dimmed));
                         }
                         let mut num_available = 0;
                         let mut last_cand = 0;
@@ -85,7 +84,7 @@ impl SudokuSolveMethod for BowmansBingoSolver {
                         }
                         if num_available == 1 {
                             candidate_states.insert((seen_row, seen_col, last_cand), CandidateState::Forced);
-                            visualizer_updates.push(VisualizerUpdate::CreateChain(row, col, num, seen_row, seen_col, last_cand, Colors::CHAIN_COLOR));
+                            visualizer_updates.push(VisualizerUpdate::CreateChain(row, col, num, seen_row, seen_col, last_cand, HighlightRole::Link));
                         }
                     }
                 } else {
@@ -111,17 +110,17 @@ impl SudokuSolveMethod for BowmansBingoSolver {
 
                     if peer_cells_with_same_num.len() > 1 {
                         for &(row, col) in peer_cells_with_same_num {
-                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                         }
                         // Contradiction found
                         visualizer_updates.push(VisualizerUpdate::SetTitle("Bowmans Bingo".to_string()));
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(start_row, start_col, start_num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(start_row, start_col, start_num, HighlightRole::Elimination));
 
-                        return Some((vec![SolverAction::CandidateReduction(start_row, start_col, start_num)], visualizer_updates));
+                        return Ok(Some((vec![SolverAction::CandidateReduction(start_row, start_col, start_num)], visualizer_updates)));
                     }
                 }
             }
         }
-        None
+        Ok(None)
     }
 }
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use super::sudoku_solver::SudokuSolveMethod;
+use super::single_candidate_solver::SingleCandidateSolver;
+use super::naked_singles_solver::HiddenSinglesSolver;
+use super::naked_candidates_solver::NakedCandidatesSolver;
+use super::hidden_candidates_solver::HiddenCandidatesSolver;
+use super::intersection_removal_solver::IntersectionRemovalSolver;
+use super::x_wing_solver::XWingSolver;
+use super::y_wing_solver::YWingSolver;
+use super::x_chain_solver::XChainSolver;
+use super::singles_chains_solver::SinglesChainsSolver;
+use super::xy_chain_solver::XYChainSolver;
+use super::swordfish_solver::SwordfishSolver;
+use super::xyz_wing_solver::XYZWingSolver;
+use super::medusa_3d_solver::Medusa3DSolver;
+use super::bowmans_bingo_solver::BowmansBingoSolver;
+
+// Difficulty tiers, a puzzle belongs to the tier of the hardest technique it needs.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+pub enum Difficulty {
+    Beginner,
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 5] = [
+        Difficulty::Beginner,
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Expert,
+    ];
+}
+
+impl Difficulty {
+    pub fn from_name(name: &str) -> Option<Difficulty> {
+        Difficulty::ALL.into_iter().find(|difficulty| difficulty.to_string().to_lowercase() == name.to_lowercase())
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Difficulty::Beginner => "Beginner",
+            Difficulty::Easy => "Easy",
+            Difficulty::Medium => "Medium",
+            Difficulty::Hard => "Hard",
+            Difficulty::Expert => "Expert",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Every solver with its tier, in the order they should be tried. The tiers are sorted easiest first, so a puzzle
+// is only rated by a technique once nothing easier makes progress.
+pub fn default_solvers() -> Vec<(Difficulty, Box<dyn SudokuSolveMethod>)> {
+    vec![
+        (Difficulty::Beginner, Box::new(SingleCandidateSolver)),
+        (Difficulty::Beginner, Box::new(HiddenSinglesSolver)),
+        (Difficulty::Easy, Box::new(NakedCandidatesSolver::<2>)),
+        (Difficulty::Easy, Box::new(NakedCandidatesSolver::<3>)),
+        (Difficulty::Easy, Box::new(HiddenCandidatesSolver::<2>)),
+        (Difficulty::Easy, Box::new(HiddenCandidatesSolver::<3>)),
+        (Difficulty::Easy, Box::new(IntersectionRemovalSolver)),
+        (Difficulty::Medium, Box::new(NakedCandidatesSolver::<4>)),
+        (Difficulty::Medium, Box::new(HiddenCandidatesSolver::<4>)),
+        (Difficulty::Medium, Box::new(XWingSolver)),
+        (Difficulty::Medium, Box::new(YWingSolver)),
+        (Difficulty::Hard, Box::new(XChainSolver)),
+        (Difficulty::Hard, Box::new(SinglesChainsSolver)),
+        (Difficulty::Hard, Box::new(SwordfishSolver::<3>)),
+        (Difficulty::Hard, Box::new(XYZWingSolver)),
+        (Difficulty::Expert, Box::new(XYChainSolver)),
+        (Difficulty::Expert, Box::new(SwordfishSolver::<4>)),
+        (Difficulty::Expert, Box::new(Medusa3DSolver)),
+        (Difficulty::Expert, Box::new(BowmansBingoSolver)),
+    ]
+}
+
+pub fn solvers_up_to(difficulty: Difficulty) -> Vec<Box<dyn SudokuSolveMethod>> {
+    default_solvers().into_iter()
+        .filter(|(tier, _)| *tier <= difficulty)
+        .map(|(_, solver)| solver)
+        .collect()
+}
+
+// Tier of a solver by its name, None if it is not one of the default solvers.
+pub fn difficulty_of(name: &str) -> Option<Difficulty> {
+    default_solvers().into_iter()
+        .find(|(_, solver)| solver.name() == name)
+        .map(|(tier, _)| tier)
+}
@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -13,13 +13,13 @@ pub struct HiddenCandidatesSolver<const NUM_CANDIDATES: usize>;
 // it does not matter if other numbers exist as those two numbers MUST 
 // go in either of those cells
 impl<const NUM_CANDIDATES: usize> SudokuSolveMethod for HiddenCandidatesSolver<NUM_CANDIDATES> {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
 
         for unit_type in [UnitType::Box, UnitType::Row, UnitType::Col] {
-            if let Some(ret) = self.check_units(sgrid, NUM_CANDIDATES, unit_type) { return Some(ret) };
+            if let Some(ret) = self.check_units(sgrid, NUM_CANDIDATES, unit_type) { return Ok(Some(ret)) };
         }
 
-        None
+        Ok(None)
     }
 }
 impl<const NUM_CANDIDATES: usize> HiddenCandidatesSolver<NUM_CANDIDATES> {
@@ -83,7 +83,7 @@ impl<const NUM_CANDIDATES: usize> HiddenCandidatesSolver<NUM_CANDIDATES> {
                 );
 
                 for &(row, col) in &unit {
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                 }
 
                 for (row, col) in hidden_candidate_candidate_cells {
@@ -93,11 +93,11 @@ impl<const NUM_CANDIDATES: usize> HiddenCandidatesSolver<NUM_CANDIDATES> {
                         if sgrid.candidates[row][col].contains(&num) {
                             for &val in &candidates_combination {
                                 if sgrid.candidates[row][col].contains(&val) {
-                                    visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, val, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                                    visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, val, HighlightRole::Pivot));
                                 }
                             }
-                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                             reductions.push(SolverAction::CandidateReduction(row, col, num));
                         }
                     }
@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::super::sudoku_grid::*;
 use super::sudoku_solver::*;
@@ -14,16 +14,16 @@ pub struct IntersectionRemovalSolver;
 // box line reductions is similar, if on any row/col you can only place a number in 2/3 places and they are all contained within
 // one box, the candidate number of that line can be removed from the rest of the box. Its like the opposite of a pointing pair.
 impl SudokuSolveMethod for IntersectionRemovalSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         for unit_type in [UnitType::Row, UnitType::Col] {
-            if let Some(ret) = self.pointing_pair(sgrid, unit_type) { return Some(ret) };
+            if let Some(ret) = self.pointing_pair(sgrid, unit_type) { return Ok(Some(ret)) };
         }
 
         for unit_type in [UnitType::Row, UnitType::Col] {
-            if let Some(ret) = self.box_line_reduction(sgrid, unit_type) { return Some(ret) };
+            if let Some(ret) = self.box_line_reduction(sgrid, unit_type) { return Ok(Some(ret)) };
         }
 
-        None
+        Ok(None)
     }
 }
 
@@ -61,12 +61,12 @@ impl IntersectionRemovalSolver {
                 ));
 
                 for &(row, col) in unit {
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                 }
 
                 for &(row, col) in &appeared_in_unit {
                     if sgrid.candidates[row][col].contains(&num) {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Pivot));
                     }
                 }
 
@@ -79,9 +79,9 @@ impl IntersectionRemovalSolver {
                         UnitType::Box => panic!("Box type makes no sense"),
                     };
                     if unit.contains(&(row, col)) { continue; }
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                     if sgrid.candidates[row][col].contains(&num) {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                         reductions.push(SolverAction::CandidateReduction(row, col, num));
                     }
                 }                
@@ -126,17 +126,17 @@ impl IntersectionRemovalSolver {
                 ));
 
                 for &(row, col) in unit {
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                     if sgrid.candidates[row][col].contains(&num) {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Pivot));
                     }
                 }
 
                 // All digits in the box except for those in the row/col unit used can be candadite reduced.
                 for &(row, col) in SudokuGrid::get_cells_in_unit_from(UnitType::Box, appeared_in_unit[0]).iter().filter(|&cord| !unit.contains(cord)) {
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                     if sgrid.candidates[row][col].contains(&num) {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                         reductions.push(SolverAction::CandidateReduction(row, col, num));
                     }
                 }                
@@ -1,34 +1,34 @@
 use std::collections::{HashMap, HashSet};
 
 use itertools::{Itertools, iproduct};
-use raylib::prelude::Color;
 
 use crate::adjacency_graph::{AdjacencyGraph, BiColor};
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
 
 pub struct Medusa3DSolver;
 
+// Keeps the default state_key, a cell emptied by colour is found more easily as the candidates of uncoloured cells shrink.
 impl SudokuSolveMethod for Medusa3DSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         let graph = (1..=9).fold(AdjacencyGraph::new(), |mut acc, num| {
             acc.merge_on_bivalue(sgrid.get_conjugate_pairs(num), &sgrid);
             acc
         });
 
-        let bicolored_graphs = AdjacencyGraph::bicolor_graphs(&graph);
+        let bicolored_graphs = AdjacencyGraph::bicolor_graphs(&graph)?;
 
         for bicolored_graph in bicolored_graphs.iter() {
             let mut visualizer_updates = Vec::new();
             let mut reductions = Vec::new();
 
-            let get_color_of_node = |(row, col, num)| -> Color {
+            let get_color_of_node = |(row, col, num)| -> HighlightRole {
                 if bicolored_graph.contains_key(&(row, col, num)) {
                     match bicolored_graph[&(row, col, num)] {
-                        BiColor::Blue => Colors::CHAIN_BLUE,
-                        BiColor::Red => Colors::CHAIN_RED,
+                        BiColor::Blue => HighlightRole::ChainOff,
+                        BiColor::Red => HighlightRole::ChainOn,
                         BiColor::None => panic!("Uncolored node in graph."),
                     }
                 } else {
@@ -39,9 +39,9 @@ impl SudokuSolveMethod for Medusa3DSolver {
             for &(row, col, num) in &graph.nodes() {
                 if !bicolored_graph.contains_key(&(row, col, num)) { continue; }
                 for &(rowb, colb, numb) in graph.neighbors((row, col, num)).unwrap() {
-                    visualizer_updates.push(VisualizerUpdate::CreateChain(row, col, num, rowb, colb, numb, Colors::CHAIN_COLOR));
+                    visualizer_updates.push(VisualizerUpdate::CreateChain(row, col, num, rowb, colb, numb, HighlightRole::Link));
                 }
-                visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                 visualizer_updates.push(VisualizerUpdate::BackgroundCandidate(row, col, num, get_color_of_node((row, col, num))));
             }
 
@@ -67,11 +67,11 @@ impl SudokuSolveMethod for Medusa3DSolver {
                     visualizer_updates.push(VisualizerUpdate::SetTitle("3d Medusa: Twice in a Cell".to_string()));
                     for (&(row, col, num), &c) in bicolored_graph.iter() {
                         if c == color {
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                             reductions.push(SolverAction::CandidateReduction(row, col, num));
                         }
                     }
-                    return Some((reductions, visualizer_updates));
+                    return Ok(Some((reductions, visualizer_updates)));
                 }
             }
 
@@ -97,11 +97,11 @@ impl SudokuSolveMethod for Medusa3DSolver {
                     visualizer_updates.push(VisualizerUpdate::SetTitle("3d Medusa: Twice in a Unit".to_string()));
                     for (&(row, col, num), &c) in bicolored_graph.iter() {
                         if c == color {
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                             reductions.push(SolverAction::CandidateReduction(row, col, num));
                         }
                     }
-                    return Some((reductions, visualizer_updates));
+                    return Ok(Some((reductions, visualizer_updates)));
                 }
             }
 
@@ -123,14 +123,14 @@ impl SudokuSolveMethod for Medusa3DSolver {
                     if !all_homogeneous {
                         let candidates_not_in_graph: Vec<usize> = sgrid.candidates[row][col].iter().filter(|&&candidate| !bicolored_graph.contains_key(&(row, col, candidate))).cloned().collect();
                         for candidate in candidates_not_in_graph {
-                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, HighlightRole::Elimination));
                             reductions.push(SolverAction::CandidateReduction(row, col, candidate));
                         }
                     }
                     
                 }
-                if !reductions.is_empty() { return Some((reductions, visualizer_updates)); }
+                if !reductions.is_empty() { return Ok(Some((reductions, visualizer_updates))); }
             }
 
             // Rule 4 Two colours 'elsewhere'
@@ -153,8 +153,8 @@ impl SudokuSolveMethod for Medusa3DSolver {
                                 for (row, col) in cells_seen_by_both {
                                     if (row, col) == (row1, col1) || (row, col) == (row2, col2) { continue; }
                                     if sgrid.candidates[row][col].contains(&num) {
-                                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
-                                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
+                                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                                         reductions.push(SolverAction::CandidateReduction(row, col, num));
                                     }
                                 }
@@ -163,7 +163,7 @@ impl SudokuSolveMethod for Medusa3DSolver {
                         }
                     }
                 }
-                if !reductions.is_empty() { return Some((reductions, visualizer_updates)); }
+                if !reductions.is_empty() { return Ok(Some((reductions, visualizer_updates))); }
             }
 
             // Rule 5 Two colours Unit + Cell
@@ -177,14 +177,14 @@ impl SudokuSolveMethod for Medusa3DSolver {
                             if row == rowb && col == colb { continue; }
 
                             if color != colorb && numb == candidate && SudokuGrid::cells_see_each_other((row, col), (rowb, colb)) {
-                                visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
-                                visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                                visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
+                                visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, HighlightRole::Elimination));
                                 reductions.push(SolverAction::CandidateReduction(row, col, candidate));
                             }
                         }
                     }
                 }
-                if !reductions.is_empty() { return Some((reductions, visualizer_updates)); }
+                if !reductions.is_empty() { return Ok(Some((reductions, visualizer_updates))); }
             }
 
             // Rule 6 Cell Emptied by Color
@@ -227,18 +227,18 @@ impl SudokuSolveMethod for Medusa3DSolver {
 
                 if let Some((cell, color)) = conflicting_color {
                     visualizer_updates.push(VisualizerUpdate::SetTitle("3d Medusa: Cell Emptied by Color".to_string()));
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(cell.0, cell.1, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(cell.0, cell.1, HighlightRole::Elimination));
                     for (&(row, col, num), &c) in bicolored_graph.iter() {
                         if c == color {
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                             reductions.push(SolverAction::CandidateReduction(row, col, num));
                         }
                     }
-                    return Some((reductions, visualizer_updates));
+                    return Ok(Some((reductions, visualizer_updates)));
                 }
             }
         }
 
-        None
+        Ok(None)
     }
 }
\ No newline at end of file
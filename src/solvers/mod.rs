@@ -13,4 +13,6 @@ pub mod bowmans_bingo_solver;
 pub mod xyz_wing_solver;
 pub mod x_chain_solver;
 pub mod xy_chain_solver;
-pub mod solver_manager;
\ No newline at end of file
+pub mod solver_manager;
+pub mod solver_statistics;
+pub mod difficulty;
\ No newline at end of file
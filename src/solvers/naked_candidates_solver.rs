@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -11,13 +11,18 @@ pub struct NakedCandidatesSolver<const NUM_CANDIDATES: usize>;
 // A Naked Candidates Solver finds a group of n digits across any unit.
 // Once found, it can remove all instances of those candidates in every shared unit.
 impl<const NUM_CANDIDATES: usize> SudokuSolveMethod for NakedCandidatesSolver<NUM_CANDIDATES> {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
 
         for unit_type in [UnitType::Box, UnitType::Row, UnitType::Col] {
-            if let Some(ret) = self.check_units(sgrid, NUM_CANDIDATES, unit_type) { return Some(ret); };
+            if let Some(ret) = self.check_units(sgrid, NUM_CANDIDATES, unit_type) { return Ok(Some(ret)); };
         }
 
-        None
+        Ok(None)
+    }
+
+    // Cells with more candidates than the group size can not be part of it.
+    fn state_key(&self, sgrid: &SudokuGrid) -> u64 {
+        candidate_fingerprint(sgrid, |row, col| sgrid.grid[row][col] == 0 && sgrid.candidates[row][col].len() <= NUM_CANDIDATES)
     }
 }
 
@@ -45,9 +50,9 @@ impl<const NUM_CANDIDATES: usize> NakedCandidatesSolver<NUM_CANDIDATES> {
                 let mut digits: HashSet<usize> = Default::default();
                 for &(row, col) in &n_cell_combination {
                     for &candidate in &sgrid.candidates[row][col] {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, HighlightRole::Pivot));
                     }
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                     for &num in &sgrid.candidates[row][col] { digits.insert(num); }
                 }
 
@@ -65,10 +70,10 @@ impl<const NUM_CANDIDATES: usize> NakedCandidatesSolver<NUM_CANDIDATES> {
                 for unit in SudokuGrid::get_contained_units(&n_cell_combination) {
                     for (row, col) in SudokuGrid::get_cells_in_unit_from(unit, n_cell_combination[0]) {
                         if n_cell_combination.contains(&&(row, col)) { continue; }
-                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                         for &num in &all_candidates {
                             if sgrid.candidates[row][col].contains(&num) {
-                                visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                                visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                                 reductions.push(SolverAction::CandidateReduction(row, col, num));
                             }
                         }
@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -8,22 +8,35 @@ pub struct HiddenSinglesSolver;
 // A Naked Singles Solver finds any unit (row, col, box) in which
 // there exists a digit that can only be placed in one of the cells
 impl SudokuSolveMethod for HiddenSinglesSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
 
         for unit_type in [UnitType::Box, UnitType::Row, UnitType::Col] {
             for unit in SudokuGrid::get_all_units_from_unit_type(unit_type) {
-                if let Some(result) = self.find_hidden_single(sgrid, &unit, unit_type) { return Some(result); }
+                if let Some(result) = self.find_hidden_singles(sgrid, &unit, unit_type).into_iter().next() { return Ok(Some(result)); }
             }
         }
 
-        None
+        Ok(None)
+    }
+
+    fn apply_all(&self, sgrid: &SudokuGrid) -> Result<Vec<SolverResult>, SolverError> {
+        let mut results = Vec::new();
+
+        for unit_type in [UnitType::Box, UnitType::Row, UnitType::Col] {
+            for unit in SudokuGrid::get_all_units_from_unit_type(unit_type) {
+                results.extend(self.find_hidden_singles(sgrid, &unit, unit_type));
+            }
+        }
+
+        Ok(results)
     }
 }
 
 impl HiddenSinglesSolver {
-    fn find_hidden_single(&self, sgrid: &SudokuGrid, vals: &Vec<(usize, usize)>, unit_type: UnitType) -> Option<SolverResult> {
+    // Finds every hidden single within the unit, each digit is reported as its own result.
+    fn find_hidden_singles(&self, sgrid: &SudokuGrid, vals: &Vec<(usize, usize)>, unit_type: UnitType) -> Vec<SolverResult> {
+        let mut results = Vec::new();
         let mut visualizer_updates = Vec::new();
-        let mut reductions = Vec::new();
 
         visualizer_updates.push(VisualizerUpdate::SetTitle("Naked Singles".to_string()));
 
@@ -34,7 +47,7 @@ impl HiddenSinglesSolver {
             for &candidate in &sgrid.candidates[row][col] {
                 candidate_count[candidate] += 1;
                 cordinate_of_candidate[candidate] = (row, col);
-                visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
             }
         }
 
@@ -43,8 +56,8 @@ impl HiddenSinglesSolver {
                 let (row, col) = cordinate_of_candidate[num];
                 // Cell is already solved, we dont need to solve it again.
                 if sgrid.grid[row][col] != 0 { continue; }
-                visualizer_updates.push(VisualizerUpdate::ColorDigit(row, col, Colors::SOLVED_DIGIT));
-                reductions.push(SolverAction::DigitSolve(row, col, num));
+                let mut visualizer_updates = visualizer_updates.clone();
+                visualizer_updates.push(VisualizerUpdate::ColorDigit(row, col, HighlightRole::Solved));
                 visualizer_updates.push(VisualizerUpdate::SetDescription(
                     format!(
                         "In the marked {}, there is only 1 valid placement for the digit {}.",
@@ -56,10 +69,10 @@ impl HiddenSinglesSolver {
                         num
                     )
                 ));
-                return Some((reductions, visualizer_updates));
+                results.push((vec![SolverAction::DigitSolve(row, col, num)], visualizer_updates));
             }
         }
 
-        None
+        results
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -8,7 +8,7 @@ pub struct SingleCandidateSolver;
 // A Single Candidate Solver finds any cells which contain only 1 candidate number.
 // Since a cell has 1 candidate only one number can be placed in the cell.
 impl SudokuSolveMethod for SingleCandidateSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         let mut visualizer_updates = Vec::new();
         let mut reductions = Vec::new();
 
@@ -19,8 +19,8 @@ impl SudokuSolveMethod for SingleCandidateSolver {
             for j in 0..9 {
                 if sgrid.grid[i][j] == 0 && sgrid.candidates[i][j].len() == 1 {
                     if let Some(digit) = sgrid.candidates[i][j].iter().next() {
-                        visualizer_updates.push(VisualizerUpdate::ColorDigit(i, j, Colors::SOLVED_DIGIT));
-                        visualizer_updates.push(VisualizerUpdate::ColorCell(i, j, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorDigit(i, j, HighlightRole::Solved));
+                        visualizer_updates.push(VisualizerUpdate::ColorCell(i, j, HighlightRole::Pivot));
                         reductions.push(SolverAction::DigitSolve(i, j, *digit));
                     }
                 }
@@ -29,9 +29,31 @@ impl SudokuSolveMethod for SingleCandidateSolver {
         
         if !reductions.is_empty() { 
             visualizer_updates.push(VisualizerUpdate::SetDescription("These cells have only one valid candidate, making it the definitive number for that cell.".to_string())); 
-            return Some((reductions, visualizer_updates))
+            return Ok(Some((reductions, visualizer_updates)))
         }
         
-        None
+        Ok(None)
+    }
+
+    fn apply_all(&self, sgrid: &SudokuGrid) -> Result<Vec<SolverResult>, SolverError> {
+        let mut results = Vec::new();
+
+        for i in 0..9 {
+            for j in 0..9 {
+                if sgrid.grid[i][j] == 0 && sgrid.candidates[i][j].len() == 1 {
+                    if let Some(&digit) = sgrid.candidates[i][j].iter().next() {
+                        let visualizer_updates = vec![
+                            VisualizerUpdate::SetTitle("Single Candidate".to_string()),
+                            VisualizerUpdate::ColorDigit(i, j, HighlightRole::Solved),
+                            VisualizerUpdate::ColorCell(i, j, HighlightRole::Pivot),
+                            VisualizerUpdate::SetDescription("This cell has only one valid candidate, making it the definitive number for that cell.".to_string()),
+                        ];
+                        results.push((vec![SolverAction::DigitSolve(i, j, digit)], visualizer_updates));
+                    }
+                }
+            }
+        }
+
+        Ok(results)
     }
 }
\ No newline at end of file
@@ -1,30 +1,29 @@
 use std::collections::HashSet;
 
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::super::sudoku_grid::SudokuGrid;
 use super::sudoku_solver::*;
 use super::super::adjacency_graph::{AdjacencyGraph, BiColor};
 
 use itertools::{Itertools, iproduct};
-use raylib::prelude::Color;
 
 pub struct SinglesChainsSolver;
 
 impl SudokuSolveMethod for SinglesChainsSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         for num in 1..=9 {
             let pairs = sgrid.get_conjugate_pairs(num);
             if pairs.is_empty() { continue; }
     
-            let bicolored_graphs = AdjacencyGraph::bicolor_graphs(&pairs);
+            let bicolored_graphs = AdjacencyGraph::bicolor_graphs(&pairs)?;
     
             for bicolored_graph in bicolored_graphs.iter() {
-                let get_color_of_node = |(row, col, num)| -> Color {
+                let get_color_of_node = |(row, col, num)| -> HighlightRole {
                     if bicolored_graph.contains_key(&(row, col, num)) {
                         match bicolored_graph[&(row, col, num)] {
-                            BiColor::Blue => Colors::CHAIN_BLUE,
-                            BiColor::Red => Colors::CHAIN_RED,
+                            BiColor::Blue => HighlightRole::ChainOff,
+                            BiColor::Red => HighlightRole::ChainOn,
                             BiColor::None => panic!("Uncolored node in graph."),
                         }
                     } else {
@@ -39,9 +38,9 @@ impl SudokuSolveMethod for SinglesChainsSolver {
                 for &(row, col, num) in &pairs.nodes() {
                     if !bicolored_graph.contains_key(&(row, col, num)) { continue; }
                     for &(rowb, colb, num) in pairs.neighbors((row, col, num)).unwrap() {
-                        visualizer_updates.push(VisualizerUpdate::CreateChain(row, col, num, rowb, colb, num, Colors::CHAIN_COLOR));
+                        visualizer_updates.push(VisualizerUpdate::CreateChain(row, col, num, rowb, colb, num, HighlightRole::Link));
                     }
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                 visualizer_updates.push(VisualizerUpdate::BackgroundCandidate(row, col, num, get_color_of_node((row, col, num))));
                 }
     
@@ -64,8 +63,8 @@ impl SudokuSolveMethod for SinglesChainsSolver {
                         if SudokuGrid::cells_see_each_other((cella.0, cella.1), (cellb.0, cellb.1)) {
                             for &(row, col, _) in color_nodes {
                                 if sgrid.candidates[row][col].contains(&num) {
-                                    visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
-                                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                                    visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
+                                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                                     reductions.push(SolverAction::CandidateReduction(row, col, num));
                                 }
                             }
@@ -85,7 +84,7 @@ impl SudokuSolveMethod for SinglesChainsSolver {
                             )
                         )
                     );  
-                    return Some((reductions, visualizer_updates)); 
+                    return Ok(Some((reductions, visualizer_updates))); 
                 }
 
                 // Two of the same color can see each other
@@ -93,8 +92,8 @@ impl SudokuSolveMethod for SinglesChainsSolver {
                     let sees_red = red_nodes.iter().any(|&red_cell| SudokuGrid::cells_see_each_other((row, col), (red_cell.0, red_cell.1)) && (red_cell.0, red_cell.1) != (row, col));
                     let sees_blue = blue_nodes.iter().any(|&blue_cell| SudokuGrid::cells_see_each_other((row, col), (blue_cell.0, blue_cell.1)) && (blue_cell.0, blue_cell.1) != (row, col));
                     if sees_red && sees_blue && sgrid.candidates[row][col].contains(&num) {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
-                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
+                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                         reductions.push(SolverAction::CandidateReduction(row, col, num));
                     }
             
@@ -108,11 +107,16 @@ impl SudokuSolveMethod for SinglesChainsSolver {
                             )
                         )
                     ); 
-                    return Some((reductions, visualizer_updates)); 
+                    return Ok(Some((reductions, visualizer_updates))); 
                 }
             }
         }
 
-        None
+        Ok(None)
+    }
+
+    // The chains are built from conjugate pairs only.
+    fn state_key(&self, sgrid: &SudokuGrid) -> u64 {
+        conjugate_pair_fingerprint(sgrid)
     }
 }
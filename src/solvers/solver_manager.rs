@@ -1,13 +1,62 @@
+use std::time::Instant;
+
 use crate::{sudoku_grid::SudokuGrid, sudoku_visualizer_builder::SudokuVisualizerBuilder};
 
-use super::sudoku_solver::{SudokuSolveMethod, SolverAction, VisualizerUpdate};
+use super::difficulty::{self, Difficulty};
+use super::solver_statistics::SolverStatistics;
+use super::sudoku_solver::{SudokuSolveMethod, SolverAction, SolverError, SoundnessViolation, VisualizerUpdate};
 
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum SolveMode {
+    // Apply only the first instance found by the first solver that fires.
+    FirstInstance,
+    // Apply every independent instance the first firing solver finds as a single batch.
+    AllInstances,
+}
+
+// Outcome of solving a grid as far as the solvers can take it.
+#[derive(Clone, Debug)]
+pub struct SolveSummary {
+    pub solved: bool,
+    pub steps: usize,
+    // Names of the solvers that made progress, in the order they were first used.
+    pub techniques_used: Vec<&'static str>,
+}
+
+impl SolveSummary {
+    pub fn uses_any(&self, techniques: &[&str]) -> bool {
+        self.techniques_used.iter().any(|used| techniques.contains(used))
+    }
+
+    // Tier of the hardest technique used, None if no technique was needed or known.
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        self.techniques_used.iter().filter_map(|name| difficulty::difficulty_of(name)).max()
+    }
+
+    // The technique of the highest tier, the one used last if several share the tier.
+    pub fn hardest_technique(&self) -> Option<&'static str> {
+        self.techniques_used.iter().copied().max_by_key(|name| difficulty::difficulty_of(name))
+    }
+}
 
 pub struct SudokuSolverManager {
     pub sgrid: SudokuGrid,
     pub current_step: usize,
     pub solvers: Vec<Box<dyn SudokuSolveMethod>>,
     pub visualizers_per_step: Vec<Vec<SudokuVisualizerBuilder>>,
+    // Name of the solver that made progress in each step, None for a step where nothing was found.
+    pub techniques_per_step: Vec<Option<&'static str>>,
+    pub solve_mode: SolveMode,
+    // When enabled every action is checked against the solution before it is applied.
+    pub verification: bool,
+    pub solution: Option<[[usize; 9]; 9]>,
+    // Statistics for each solver, in the same order as the solvers.
+    pub statistics: Vec<SolverStatistics>,
+    // Statistics are only collected when enabled, timing every call is not free and Instant is not available on every target.
+    pub collect_statistics: bool,
+    // When enabled a solver that found nothing is skipped until the candidates change.
+    pub adaptive: bool,
+    negative_cache: Vec<Option<u64>>,
 }
 
 impl SudokuSolverManager {
@@ -17,6 +66,14 @@ impl SudokuSolverManager {
             solvers: Default::default(),
             current_step: 0,
             visualizers_per_step: Default::default(),
+            techniques_per_step: Default::default(),
+            solve_mode: SolveMode::FirstInstance,
+            verification: false,
+            solution: None,
+            statistics: Default::default(),
+            collect_statistics: false,
+            adaptive: false,
+            negative_cache: Default::default(),
         }
     }
 
@@ -24,57 +81,176 @@ impl SudokuSolverManager {
         self.sgrid = sgrid;
         self.current_step = 0;
         self.visualizers_per_step = Default::default();
+        self.techniques_per_step = Default::default();
+        self.solution = None;
+        self.negative_cache.iter_mut().for_each(|entry| *entry = None);
+        self.reset_statistics();
+    }
+
+    // The solution is computed once on the next iteration, every following action is verified against it.
+    // The grid is expected to have a unique solution.
+    pub fn enable_verification(&mut self) {
+        self.verification = true;
+    }
+
+    pub fn disable_verification(&mut self) {
+        self.verification = false;
+        self.solution = None;
     }
 
     pub fn add_solver(&mut self, solver: Box<dyn SudokuSolveMethod>) {
+        self.statistics.push(SolverStatistics::new(solver.name()));
+        self.negative_cache.push(None);
         self.solvers.push(solver);
     }
 
-    pub fn solve_iteration(&mut self) -> bool {
-        let mut applied_solver = false;
+    pub fn set_collect_statistics(&mut self, collect_statistics: bool) {
+        self.collect_statistics = collect_statistics;
+    }
+
+    pub fn set_adaptive(&mut self, adaptive: bool) {
+        self.adaptive = adaptive;
+        self.negative_cache.iter_mut().for_each(|entry| *entry = None);
+    }
+
+    pub fn reset_statistics(&mut self) {
+        for stats in self.statistics.iter_mut() {
+            *stats = SolverStatistics::new(stats.name);
+        }
+    }
+
+    // Reorders the solvers so the ones with the most hits per second spent run first.
+    // This changes which technique explains a step, so it is meant for solving rather than teaching.
+    pub fn sort_solvers_by_cost_benefit(&mut self) {
+        let mut order: Vec<usize> = (0..self.solvers.len()).collect();
+        order.sort_by(|&a, &b| self.statistics[b].cost_benefit().total_cmp(&self.statistics[a].cost_benefit()));
+
+        let mut solvers: Vec<Option<Box<dyn SudokuSolveMethod>>> = self.solvers.drain(..).map(Some).collect();
+        self.solvers = order.iter().map(|&index| solvers[index].take().unwrap()).collect();
+        self.statistics = order.iter().map(|&index| self.statistics[index].clone()).collect();
+        self.negative_cache = order.iter().map(|&index| self.negative_cache[index]).collect();
+    }
+
+    pub fn statistics_report(&self) -> String {
+        SolverStatistics::report(&self.statistics)
+    }
+
+    pub fn set_solve_mode(&mut self, solve_mode: SolveMode) {
+        self.solve_mode = solve_mode;
+    }
+
+    // Each step is logged as a pre step board, one visualizer per applied instance and a post step board.
+    // A step that fails leaves the grid and the log as they were before it.
+    pub fn solve_iteration(&mut self) -> Result<bool, SolverError> {
+        let sgrid = self.sgrid.clone();
+        let (current_step, logged_steps) = (self.current_step, self.visualizers_per_step.len());
+        let (statistics, negative_cache) = (self.statistics.clone(), self.negative_cache.clone());
+        let result = self.apply_step();
+        if result.is_err() {
+            self.sgrid = sgrid;
+            self.current_step = current_step;
+            self.visualizers_per_step.truncate(logged_steps);
+            self.techniques_per_step.truncate(logged_steps);
+            self.statistics = statistics;
+            self.negative_cache = negative_cache;
+        }
+        result
+    }
+
+    fn apply_step(&mut self) -> Result<bool, SolverError> {
+        let violations = self.sgrid.validate();
+        if !violations.is_empty() {
+            return Err(SolverError::InvalidState(violations));
+        }
+        if self.verification && self.solution.is_none() {
+            let mut solution = self.sgrid.clone();
+            if !solution.backtrack_fill() { return Err(SolverError::NoSolution); }
+            self.solution = Some(solution.grid);
+        }
+
         self.current_step += 1;
-        let mut visualizer: SudokuVisualizerBuilder = SudokuVisualizerBuilder::from_sudoku(&self.sgrid);
+        let pre_step_visualizer: SudokuVisualizerBuilder = SudokuVisualizerBuilder::from_sudoku(&self.sgrid);
         // Add Pre step visualizer of the board
         {
-            let mut cl = visualizer.clone();
+            let mut cl = pre_step_visualizer.clone();
             cl.set_title(format!("Pre step {}", self.current_step).as_str());
             self.visualizers_per_step.push(Default::default());
             self.visualizers_per_step.last_mut().unwrap().push(cl);
         }
-        for solver in &self.solvers {
-            let solver_result = solver.apply(&self.sgrid);
-            if solver_result.is_none() { continue; }
-            let (reductions, visualizer_updates) = solver_result.unwrap();
-
-            // Apply grid reductions
-            for action in reductions {
-                match action {
-                    SolverAction::DigitSolve(row, col, digit) => {
-                        assert!(self.sgrid.add_digit(digit, row, col));
-                    },
-                    SolverAction::CandidateReduction(row, col, digit) => {
-                        self.sgrid.candidates[row][col].remove(&digit);
-                    },
+
+        let mut instance_visualizers: Vec<SudokuVisualizerBuilder> = Vec::new();
+        let mut applied_technique = None;
+        for (index, solver) in self.solvers.iter().enumerate() {
+            // Each solver is keyed on the part of the grid it depends on, so progress elsewhere does not undo the skip
+            let state_key = if self.adaptive { Some(solver.state_key(&self.sgrid)) } else { None };
+            if state_key.is_some() && self.negative_cache[index] == state_key {
+                if self.collect_statistics {
+                    self.statistics[index].skipped += 1;
                 }
+                continue;
             }
 
-            // Apply visualiztion additions
-            for update in &visualizer_updates {
-                match *update {
-                    VisualizerUpdate::SetTitle(ref title) => visualizer.set_title(title),
-                    VisualizerUpdate::ColorDigit(row, col, color) => visualizer.color_digit(row, col, self.sgrid.grid[row][col], color),
-                    VisualizerUpdate::ColorCell(row, col, color) => visualizer.color_cell(row, col, color),
-                    VisualizerUpdate::ColorCandidate(row, col, num, color) => visualizer.color_candidate(row, col, num, color),
-                    VisualizerUpdate::BackgroundCandidate(row, col, num, color) => visualizer.highlight_candidate(row, col, num, color),
-                    VisualizerUpdate::CreateChain(row_from, col_from, num_from, row_to, col_to, num_to, color) => visualizer.add_chain(row_from, col_from, num_from, row_to, col_to, num_to, color),
-                    VisualizerUpdate::SetDescription(ref description) => visualizer.set_description(description),
+            let start = if self.collect_statistics { Some(Instant::now()) } else { None };
+            let solver_results: Vec<_> = match self.solve_mode {
+                SolveMode::FirstInstance => solver.apply(&self.sgrid)?.into_iter().collect(),
+                SolveMode::AllInstances => solver.apply_all(&self.sgrid)?,
+            };
+            if let Some(start) = start {
+                self.statistics[index].record_call(start.elapsed(), !solver_results.is_empty());
+            }
+            self.negative_cache[index] = if solver_results.is_empty() { state_key } else { None };
+
+            for (reductions, visualizer_updates) in solver_results {
+                // Instances found in the same state can overlap, skip any that an earlier instance already covered.
+                if !reductions.iter().any(|action| SudokuSolverManager::makes_progress(&self.sgrid, action)) { continue; }
+
+                if let Some(solution) = &self.solution {
+                    if let Some(violation) = SudokuSolverManager::find_soundness_violation(&self.sgrid, solution, &reductions, &visualizer_updates) {
+                        return Err(SolverError::UnsoundAction(Box::new(violation)));
+                    }
                 }
+
+                // Apply grid reductions
+                for action in reductions {
+                    match action {
+                        SolverAction::DigitSolve(row, col, digit) => {
+                            if self.sgrid.grid[row][col] == digit { continue; }
+                            if !self.sgrid.add_digit(digit, row, col) {
+                                return Err(SolverError::InvalidPlacement(row, col, digit));
+                            }
+                        },
+                        SolverAction::CandidateReduction(row, col, digit) => {
+                            self.sgrid.candidates[row][col].remove(&digit);
+                        },
+                    }
+                }
+
+                let mut visualizer = pre_step_visualizer.clone();
+                SudokuSolverManager::apply_visualizer_updates(&mut visualizer, &visualizer_updates, &self.sgrid);
+                instance_visualizers.push(visualizer);
             }
-            applied_solver = true;
-            break;
+
+            if !instance_visualizers.is_empty() {
+                applied_technique = Some(solver.name());
+                break;
+            }
+        }
+
+        let applied_solver = !instance_visualizers.is_empty();
+        self.techniques_per_step.push(applied_technique);
+        if !applied_solver {
+            instance_visualizers.push(pre_step_visualizer);
+        }
+
+        let instance_count = instance_visualizers.len();
+        for (i, mut visualizer) in instance_visualizers.into_iter().enumerate() {
+            if instance_count == 1 {
+                visualizer.set_title(format!("{} - Step {}", visualizer.title, self.current_step).as_str());
+            } else {
+                visualizer.set_title(format!("{} - Step {} ({}/{})", visualizer.title, self.current_step, i + 1, instance_count).as_str());
+            }
+            self.visualizers_per_step.last_mut().unwrap().push(visualizer);
         }
-        visualizer.set_title(format!("{} - Step {}", visualizer.title, self.current_step).as_str());
-        self.visualizers_per_step.last_mut().unwrap().push(visualizer);
 
         // Add Post step visualizer of the board
         {
@@ -83,9 +259,73 @@ impl SudokuSolverManager {
             self.visualizers_per_step.last_mut().unwrap().push(cl);
         }
 
-        // println!("Solvable: {}", self.sgrid.has_unique_solution());
-        debug_assert!(self.sgrid.has_unique_solution(), "After applying solver [{}] we do not have a solution.", &self.visualizers_per_step.last().unwrap()[1].title);
-        applied_solver
+        Ok(applied_solver)
+    }
+
+    // Runs solve iterations until no solver makes progress and summarizes the steps taken.
+    pub fn solve(&mut self) -> Result<SolveSummary, SolverError> {
+        let first_step = self.techniques_per_step.len();
+        while self.solve_iteration()? {}
+
+        let mut techniques_used: Vec<&'static str> = Vec::new();
+        for &technique in self.techniques_per_step[first_step..].iter().flatten() {
+            if !techniques_used.contains(&technique) {
+                techniques_used.push(technique);
+            }
+        }
+
+        Ok(SolveSummary {
+            solved: self.sgrid.grid.iter().flatten().all(|&digit| digit != 0),
+            steps: self.techniques_per_step[first_step..].iter().flatten().count(),
+            techniques_used,
+        })
+    }
+
+    // An action makes progress if it still changes the grid.
+    fn makes_progress(sgrid: &SudokuGrid, action: &SolverAction) -> bool {
+        match *action {
+            SolverAction::DigitSolve(row, col, digit) => sgrid.grid[row][col] != digit,
+            SolverAction::CandidateReduction(row, col, digit) => sgrid.candidates[row][col].contains(&digit),
+        }
+    }
+
+    fn find_soundness_violation(sgrid: &SudokuGrid, solution: &[[usize; 9]; 9], reductions: &[SolverAction], visualizer_updates: &[VisualizerUpdate]) -> Option<SoundnessViolation> {
+        let unsound_action = reductions.iter().find(|&&action| match action {
+            SolverAction::CandidateReduction(row, col, digit) => solution[row][col] == digit,
+            SolverAction::DigitSolve(row, col, digit) => solution[row][col] != digit,
+        })?;
+
+        // The last title set by the solver names the technique, as it is in the visualizer.
+        let technique = visualizer_updates.iter().rev()
+            .find_map(|update| match update {
+                VisualizerUpdate::SetTitle(title) => Some(title.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let (row, col) = unsound_action.cell();
+
+        Some(SoundnessViolation {
+            technique,
+            action: *unsound_action,
+            solution_digit: solution[row][col],
+            sgrid: sgrid.clone(),
+        })
+    }
+
+    fn apply_visualizer_updates(visualizer: &mut SudokuVisualizerBuilder, visualizer_updates: &[VisualizerUpdate], sgrid: &SudokuGrid) {
+        // Apply visualiztion additions
+        for update in visualizer_updates {
+            match *update {
+                VisualizerUpdate::SetTitle(ref title) => visualizer.set_title(title),
+                VisualizerUpdate::ColorDigit(row, col, role) => visualizer.color_digit(row, col, sgrid.grid[row][col], role),
+                VisualizerUpdate::ColorCell(row, col, role) => visualizer.color_cell(row, col, role),
+                VisualizerUpdate::ColorCandidate(row, col, num, role) => visualizer.color_candidate(row, col, num, role),
+                VisualizerUpdate::BackgroundCandidate(row, col, num, role) => visualizer.highlight_candidate(row, col, num, role),
+                VisualizerUpdate::CreateChain(row_from, col_from, num_from, row_to, col_to, num_to, role) => visualizer.add_chain(row_from, col_from, num_from, row_to, col_to, num_to, role),
+                VisualizerUpdate::SetDescription(ref description) => visualizer.set_description(description),
+            }
+        }
     }
 
 }
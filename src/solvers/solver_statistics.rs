@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+// Tracks how often a solver is called, how often it finds something and the time it takes.
+#[derive(Clone, Debug, Default)]
+pub struct SolverStatistics {
+    pub name: &'static str,
+    pub calls: usize,
+    pub hits: usize,
+    // Calls avoided because the solver already found nothing in the same candidate state.
+    pub skipped: usize,
+    pub time_spent: Duration,
+}
+
+impl SolverStatistics {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    pub fn hit_rate(&self) -> f64 {
+        if self.calls == 0 { return 0.0; }
+        self.hits as f64 / self.calls as f64
+    }
+
+    pub fn average_time(&self) -> Duration {
+        if self.calls == 0 { return Duration::ZERO; }
+        self.time_spent / self.calls as u32
+    }
+
+    // Hits found per second spent in the solver, higher is cheaper progress.
+    pub fn cost_benefit(&self) -> f64 {
+        let seconds = self.time_spent.as_secs_f64();
+        if seconds == 0.0 { return 0.0; }
+        self.hits as f64 / seconds
+    }
+
+    pub fn record_call(&mut self, time: Duration, hit: bool) {
+        self.calls += 1;
+        self.time_spent += time;
+        if hit { self.hits += 1; }
+    }
+
+    // Adds the counts of another run of the same solver.
+    pub fn merge(&mut self, other: &SolverStatistics) {
+        self.calls += other.calls;
+        self.hits += other.hits;
+        self.skipped += other.skipped;
+        self.time_spent += other.time_spent;
+    }
+
+    // Formats statistics as a table sorted by cost/benefit.
+    pub fn report(statistics: &[SolverStatistics]) -> String {
+        let mut sorted: Vec<&SolverStatistics> = statistics.iter().collect();
+        sorted.sort_by(|a, b| b.cost_benefit().total_cmp(&a.cost_benefit()));
+
+        let mut report = format!("{:<28} {:>8} {:>8} {:>8} {:>9} {:>12} {:>12} {:>12}\n", "Solver", "Calls", "Hits", "Skipped", "Hit rate", "Total ms", "Avg us", "Hits/s");
+        for stats in sorted {
+            report.push_str(&format!(
+                "{:<28} {:>8} {:>8} {:>8} {:>8.1}% {:>12.2} {:>12.2} {:>12.1}\n",
+                stats.name,
+                stats.calls,
+                stats.hits,
+                stats.skipped,
+                stats.hit_rate() * 100.0,
+                stats.time_spent.as_secs_f64() * 1000.0,
+                stats.average_time().as_secs_f64() * 1_000_000.0,
+                stats.cost_benefit(),
+            ));
+        }
+        report
+    }
+}
@@ -1,25 +1,152 @@
-use raylib::prelude::Color;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
-use crate::sudoku_grid::SudokuGrid;
+use crate::theme::HighlightRole;
 
+use crate::sudoku_grid::{GridViolation, SudokuGrid, UnitType};
+
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub enum SolverAction {
     CandidateReduction(usize, usize, usize),
     DigitSolve(usize, usize, usize)
 }
 
+impl SolverAction {
+    pub fn cell(&self) -> (usize, usize) {
+        match *self {
+            SolverAction::CandidateReduction(row, col, _) => (row, col),
+            SolverAction::DigitSolve(row, col, _) => (row, col),
+        }
+    }
+}
+
+impl fmt::Display for SolverAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SolverAction::CandidateReduction(row, col, digit) => write!(f, "remove candidate {} from {}", digit, SudokuGrid::cell_to_str((row, col))),
+            SolverAction::DigitSolve(row, col, digit) => write!(f, "place digit {} in {}", digit, SudokuGrid::cell_to_str((row, col))),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub enum VisualizerUpdate {
     SetTitle(String),
     SetDescription(String),
-    ColorDigit(usize, usize, Color),
-    ColorCell(usize, usize, Color),
-    ColorCandidate(usize, usize, usize, Color),
-    BackgroundCandidate(usize, usize, usize, Color),
-    CreateChain(usize, usize, usize, usize, usize, usize, Color),
+    ColorDigit(usize, usize, HighlightRole),
+    ColorCell(usize, usize, HighlightRole),
+    ColorCandidate(usize, usize, usize, HighlightRole),
+    BackgroundCandidate(usize, usize, usize, HighlightRole),
+    CreateChain(usize, usize, usize, usize, usize, usize, HighlightRole),
 }
 
 pub type SolverResult = (Vec<SolverAction>, Vec<VisualizerUpdate>);
 
+// Describes a solver action that contradicts the known solution of the grid.
+#[derive(Clone, Debug)]
+pub struct SoundnessViolation {
+    pub technique: String,
+    pub action: SolverAction,
+    pub solution_digit: usize,
+    pub sgrid: SudokuGrid,
+}
+
+impl fmt::Display for SoundnessViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Unsound action from technique [{}]: {}.", self.technique, self.action)?;
+        writeln!(f, "The solution has the digit {} in {}.", self.solution_digit, SudokuGrid::cell_to_str(self.action.cell()))?;
+        writeln!(f, "Grid before the action was applied:")?;
+        write!(f, "{}", self.sgrid)
+    }
+}
+
+// Errors are raised for grid states the solvers can't reason about instead of panicking.
+#[derive(Clone, Debug)]
+pub enum SolverError {
+    // Two linked candidates ended up with the same color, the graph has an odd cycle.
+    NotBipartite((usize, usize, usize)),
+    // The digit is not a candidate of the cell or placing it empties a peer cell.
+    InvalidPlacement(usize, usize, usize),
+    // The grid contradicts itself and can no longer be solved.
+    InvalidState(Vec<GridViolation>),
+    // The grid has no solution to verify solver actions against.
+    NoSolution,
+    UnsoundAction(Box<SoundnessViolation>),
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolverError::NotBipartite(node) => write!(f, "The candidate graph is not bipartite at {}.", SudokuGrid::cell_candidate_to_str(*node)),
+            SolverError::InvalidPlacement(row, col, digit) => write!(f, "Unable to place the digit {} in {}.", digit, SudokuGrid::cell_to_str((*row, *col))),
+            SolverError::InvalidState(violations) => write!(
+                f,
+                "The grid is in an invalid state. {}",
+                violations.iter().map(|violation| violation.to_string()).collect::<Vec<_>>().join(" ")
+            ),
+            SolverError::NoSolution => write!(f, "The grid has no solution."),
+            SolverError::UnsoundAction(violation) => write!(f, "{}", violation),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
 pub trait SudokuSolveMethod {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult>;
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError>;
+
+    // Returns every independent instance of the technique in the current state, one result per instance.
+    // Solvers that can only report a single instance fall back to apply.
+    fn apply_all(&self, sgrid: &SudokuGrid) -> Result<Vec<SolverResult>, SolverError> {
+        Ok(self.apply(sgrid)?.into_iter().collect())
+    }
+
+    // Hash of the part of the grid the result depends on, the adaptive manager skips the solver while it is unchanged
+    // after the solver found nothing. Candidates only shrink during a solve, so cells where fewer candidates can only
+    // mean fewer eliminations may be left out.
+    fn state_key(&self, sgrid: &SudokuGrid) -> u64 {
+        candidate_fingerprint(sgrid, |_, _| true)
+    }
+
+    // Name of the solver type without its module path, used to identify it in statistics.
+    fn name(&self) -> &'static str {
+        let type_name = std::any::type_name::<Self>();
+        let path_end = type_name.find('<').unwrap_or(type_name.len());
+        match type_name[..path_end].rfind("::") {
+            Some(index) => &type_name[index + 2..],
+            None => type_name,
+        }
+    }
 }
 
+
+// Hash of the digits and candidates of the included cells, equal fingerprints mean a solver sees the same state.
+pub fn candidate_fingerprint<F: Fn(usize, usize) -> bool>(sgrid: &SudokuGrid, include: F) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for row in 0..9 {
+        for col in 0..9 {
+            if !include(row, col) { continue; }
+            (row, col, sgrid.grid[row][col]).hash(&mut hasher);
+            let mask = sgrid.candidates[row][col].iter().fold(0u16, |mask, &digit| mask | 1 << digit);
+            mask.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+// Hash of the conjugate pairs of every digit, the units where the digit is left in exactly two cells.
+pub fn conjugate_pair_fingerprint(sgrid: &SudokuGrid) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for unit_type in [UnitType::Row, UnitType::Col, UnitType::Box] {
+        for (index, unit) in SudokuGrid::get_all_units_from_unit_type(unit_type).iter().enumerate() {
+            for num in 1..=9 {
+                let cells: Vec<&(usize, usize)> = unit.iter().filter(|&&(row, col)| sgrid.candidates[row][col].contains(&num)).collect();
+                if cells.len() == 2 {
+                    (index, num, cells).hash(&mut hasher);
+                }
+            }
+        }
+    }
+    hasher.finish()
+}
@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -9,11 +9,11 @@ use itertools::Itertools;
 pub struct SwordfishSolver<const FISH_SIZE: usize>;
 
 impl<const FISH_SIZE: usize> SudokuSolveMethod for SwordfishSolver<FISH_SIZE> {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         for unit_type in [UnitType::Row, UnitType::Col] {
-            if let Some(ret) = SwordfishSolver::<FISH_SIZE>::apply_fish_on_axis(sgrid, unit_type) { return Some(ret) };
+            if let Some(ret) = SwordfishSolver::<FISH_SIZE>::apply_fish_on_axis(sgrid, unit_type) { return Ok(Some(ret)) };
         }
-        None
+        Ok(None)
     }
 }
 
@@ -66,9 +66,9 @@ impl<const FISH_SIZE: usize> SwordfishSolver<FISH_SIZE> {
     
                 for cell_set in &unit_set {
                     for (row, col) in SudokuGrid::get_cells_in_unit_from(unit_type, cell_set[0]) {
-                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                         if sgrid.candidates[row][col].contains(&num) {
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Pivot));
                         }
                     }
                 }
@@ -91,9 +91,9 @@ impl<const FISH_SIZE: usize> SwordfishSolver<FISH_SIZE> {
                                 _ => {}
                             }
                     
-                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                            visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                             if sgrid.candidates[row][col].contains(&num) {
-                                visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                                visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                                 reductions.push(SolverAction::CandidateReduction(row, col, num));
                             }
                         }
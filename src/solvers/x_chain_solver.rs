@@ -1,8 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{sudoku_grid::SudokuGrid, sudoku_visualizer_builder::Colors, adjacency_graph::AdjacencyGraph, solvers::sudoku_solver::VisualizerUpdate};
+use crate::{sudoku_grid::SudokuGrid, theme::HighlightRole, adjacency_graph::AdjacencyGraph, solvers::sudoku_solver::VisualizerUpdate};
 
-use super::sudoku_solver::{SolverAction::*, SolverResult, SudokuSolveMethod, VisualizerUpdate::*};
+use super::sudoku_solver::{conjugate_pair_fingerprint, SolverAction::*, SolverError, SolverResult, SudokuSolveMethod, VisualizerUpdate::*};
 
 pub struct XChainSolver;
 
@@ -10,7 +10,7 @@ pub struct XChainSolver;
 // Weak links and it must start and end on a strong link.
 
 impl SudokuSolveMethod for XChainSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         for chain_length in (3..=9).step_by(2) {
             for num in 1..=9 {
                 let mut conjugate_pairs= sgrid.get_conjugate_pairs(num);
@@ -59,7 +59,7 @@ impl SudokuSolveMethod for XChainSolver {
                             visualizer_updates.push(ColorCell(
                                 row,
                                 col,
-                                Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL,
+                                HighlightRole::Elimination,
                             ));
                             if sgrid.candidates[row][col].contains(&num) {
                                 reductions.push(CandidateReduction(row, col, num));
@@ -67,15 +67,15 @@ impl SudokuSolveMethod for XChainSolver {
                                     row,
                                     col,
                                     num,
-                                    Colors::CANDIDATE_MARKED_FOR_REMOVAL,
+                                    HighlightRole::Elimination,
                                 ));
                             }
                         }
 
                         for i in 0..chain.len() {
                             let candidate_color = match i % 2 == 0 {
-                                true => Colors::CHAIN_BLUE,
-                                false => Colors::CHAIN_RED,
+                                true => HighlightRole::ChainOff,
+                                false => HighlightRole::ChainOn,
                             };
                             visualizer_updates.push(BackgroundCandidate(
                                 chain[i].0,
@@ -85,8 +85,8 @@ impl SudokuSolveMethod for XChainSolver {
                             ));
                             if i != chain.len() - 1 {
                                 let chain_color = match is_strong_link.get(&(chain[i], chain[i+1])) {
-                                    Some(true) => Colors::CHAIN_STRONG,
-                                    Some(false) => Colors::CHAIN_WEAK,
+                                    Some(true) => HighlightRole::StrongLink,
+                                    Some(false) => HighlightRole::WeakLink,
                                     _ => panic!("This shouldn't be possible.")
                                 };
                                 visualizer_updates.push(CreateChain(
@@ -110,13 +110,18 @@ impl SudokuSolveMethod for XChainSolver {
                                     )
                                 )
                             );  
-                            return Some((reductions, visualizer_updates)); 
+                            return Ok(Some((reductions, visualizer_updates))); 
                         }
                     }
                 }
             }
         }
-        None
+        Ok(None)
+    }
+
+    // The strong links are the conjugate pairs, fewer candidates elsewhere only remove weak links and eliminations.
+    fn state_key(&self, sgrid: &SudokuGrid) -> u64 {
+        conjugate_pair_fingerprint(sgrid)
     }
 }
 impl XChainSolver {
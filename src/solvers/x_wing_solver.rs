@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -9,16 +9,16 @@ use itertools::Itertools;
 pub struct XWingSolver;
 
 impl SudokuSolveMethod for XWingSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
 
         // X-wing, any given number if it only appears in two cells in the same 
         // [row|col] and in matching [col|row] in another [row|col] then all
         // candidates of the same value can be removed from the row & col, except for those cells
         for unit_type in [UnitType::Row, UnitType::Col] {
-            if let Some(ret) = self.apply_x_wing_on_axis(sgrid, unit_type) { return Some(ret) };
+            if let Some(ret) = self.apply_x_wing_on_axis(sgrid, unit_type) { return Ok(Some(ret)) };
         }
 
-        None
+        Ok(None)
     }
 }
 
@@ -71,9 +71,9 @@ impl XWingSolver {
 
                 for cell_pair in &unit_pair {
                     for (row, col) in SudokuGrid::get_cells_in_unit_from(unit_type, cell_pair[0]) {
-                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Pivot));
                         if sgrid.candidates[row][col].contains(&num) {
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Pivot));
                         }
                     }
                 }
@@ -83,8 +83,8 @@ impl XWingSolver {
                 x_wing_cells.sort();
                 let diagonal1 = (x_wing_cells[0], x_wing_cells[3]);
                 let diagonal2 = (x_wing_cells[1], x_wing_cells[2]);
-                visualizer_updates.push(VisualizerUpdate::CreateChain(diagonal1.0.0, diagonal1.0.1, num, diagonal1.1.0, diagonal1.1.1, num, Colors::CHAIN_COLOR));
-                visualizer_updates.push(VisualizerUpdate::CreateChain(diagonal2.0.0, diagonal2.0.1, num, diagonal2.1.0, diagonal2.1.1, num, Colors::CHAIN_COLOR));
+                visualizer_updates.push(VisualizerUpdate::CreateChain(diagonal1.0.0, diagonal1.0.1, num, diagonal1.1.0, diagonal1.1.1, num, HighlightRole::Link));
+                visualizer_updates.push(VisualizerUpdate::CreateChain(diagonal2.0.0, diagonal2.0.1, num, diagonal2.1.0, diagonal2.1.1, num, HighlightRole::Link));
 
                 for &cell_in_xwing in unit_pair[0] {
                     for (row, col) in SudokuGrid::get_cells_in_unit_from(match unit_type {
@@ -93,9 +93,9 @@ impl XWingSolver {
                         UnitType::Box => panic!("Box unit type does not make sense for an x-wing"),
                     }, cell_in_xwing) {
                         if unit_pair[0].contains(&(row, col)) || unit_pair[1].contains(&(row, col)) { continue; }
-                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                         if sgrid.candidates[row][col].contains(&num) {
-                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                            visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, num, HighlightRole::Elimination));
                             reductions.push(SolverAction::CandidateReduction(row, col, num));
                         }
                     }
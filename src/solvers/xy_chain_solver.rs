@@ -2,9 +2,9 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::{iproduct, Itertools};
 
-use crate::{sudoku_grid::SudokuGrid, sudoku_visualizer_builder::Colors, adjacency_graph::AdjacencyGraph};
+use crate::{sudoku_grid::SudokuGrid, theme::HighlightRole, adjacency_graph::AdjacencyGraph};
 
-use super::sudoku_solver::{SolverAction::*, SolverResult, SudokuSolveMethod, VisualizerUpdate::*};
+use super::sudoku_solver::{candidate_fingerprint, SolverAction::*, SolverError, SolverResult, SudokuSolveMethod, VisualizerUpdate::*};
 
 pub struct XYChainSolver;
 
@@ -12,7 +12,7 @@ pub struct XYChainSolver;
 // Weak links and it must start and end on a strong link.
 
 impl SudokuSolveMethod for XYChainSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
         let bi_valued_cells: Vec<(usize, usize)> = iproduct!(0..9, 0..9).filter(|&(row, col)| sgrid.candidates[row][col].len() == 2).collect();
         let mut is_strong_link: HashMap<((usize, usize, usize), (usize, usize, usize)), bool> = Default::default();
         let mut graph = AdjacencyGraph::new();
@@ -102,7 +102,7 @@ impl SudokuSolveMethod for XYChainSolver {
                             viualizer_updates.push(ColorCell(
                                 row,
                                 col,
-                                Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL,
+                                HighlightRole::Elimination,
                             ));
                             if sgrid.candidates[row][col].contains(&candidate_for_removal) {
                                 reductions.push(CandidateReduction(row, col, candidate_for_removal));
@@ -110,15 +110,15 @@ impl SudokuSolveMethod for XYChainSolver {
                                     row,
                                     col,
                                     candidate_for_removal,
-                                    Colors::CANDIDATE_MARKED_FOR_REMOVAL,
+                                    HighlightRole::Elimination,
                                 ));
                             }
                         }
 
                         for i in 0..chain.len() {
                             let candidate_color = match i % 2 == 0 {
-                                true => Colors::CHAIN_BLUE,
-                                false => Colors::CHAIN_RED,
+                                true => HighlightRole::ChainOff,
+                                false => HighlightRole::ChainOn,
                             };
                             viualizer_updates.push(BackgroundCandidate(
                                 chain[i].0,
@@ -128,8 +128,8 @@ impl SudokuSolveMethod for XYChainSolver {
                             ));
                             if i != chain.len() - 1 {
                                 let chain_color = match is_strong_link.get(&(chain[i], chain[i+1])) {
-                                    Some(true) => Colors::CHAIN_STRONG,
-                                    Some(false) => Colors::CHAIN_WEAK,
+                                    Some(true) => HighlightRole::StrongLink,
+                                    Some(false) => HighlightRole::WeakLink,
                                     _ => panic!("This shouldn't be possible.")
                                 };
                                 viualizer_updates.push(CreateChain(
@@ -144,12 +144,17 @@ impl SudokuSolveMethod for XYChainSolver {
                             }
                         }
 
-                        if !reductions.is_empty() { return Some((reductions, viualizer_updates)); }
+                        if !reductions.is_empty() { return Ok(Some((reductions, viualizer_updates))); }
                     }
                 }
             }
         }
-        None
+        Ok(None)
+    }
+
+    // Every link of the chain is between cells with exactly two candidates.
+    fn state_key(&self, sgrid: &SudokuGrid) -> u64 {
+        candidate_fingerprint(sgrid, |row, col| sgrid.candidates[row][col].len() == 2)
     }
 }
 
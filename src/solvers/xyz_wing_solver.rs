@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -10,7 +10,7 @@ use itertools::{Itertools, iproduct};
 pub struct XYZWingSolver;
 
 impl SudokuSolveMethod for XYZWingSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
 
         // Find all possible y-wing hinges and wings
         let cells_with_three_candidates: Vec<(usize, usize)> = iproduct!(0..9, 0..9)
@@ -92,25 +92,31 @@ impl SudokuSolveMethod for XYZWingSolver {
                 visualizer_updates.push(VisualizerUpdate::SetTitle("XYZ-Wing".to_string()));
 
                 for (row, col) in [hinge, wings[0], wings[1]] {
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                    let role = if (row, col) == hinge { HighlightRole::Pivot } else { HighlightRole::Pincer };
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, role));
                     for &candidate in &sgrid.candidates[row][col] {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, HighlightRole::Pivot));
                     }
                 }
 
                 for cell in shared_cells {
                     if cell == hinge || cell == wings[0] || cell == wings[1] { continue; }
                     let (row, col) = cell;
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                     if sgrid.candidates[row][col].contains(&z) {
                         reductions.push(SolverAction::CandidateReduction(row, col, z));
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, z, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, z, HighlightRole::Elimination));
                     }
                 }
-                if !reductions.is_empty() { return Some((reductions, visualizer_updates)); }
+                if !reductions.is_empty() { return Ok(Some((reductions, visualizer_updates))); }
             }
         }
 
-        None
+        Ok(None)
+    }
+
+    // The hinge has three candidates and the wings two.
+    fn state_key(&self, sgrid: &SudokuGrid) -> u64 {
+        candidate_fingerprint(sgrid, |row, col| (2..=3).contains(&sgrid.candidates[row][col].len()))
     }
 }
@@ -1,4 +1,4 @@
-use crate::sudoku_visualizer_builder::Colors;
+use crate::theme::HighlightRole;
 
 use super::sudoku_solver::*;
 use super::super::sudoku_grid::*;
@@ -8,7 +8,7 @@ use itertools::{Itertools, iproduct};
 pub struct YWingSolver;
 
 impl SudokuSolveMethod for YWingSolver {
-    fn apply(&self, sgrid: &SudokuGrid) -> Option<SolverResult> {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
 
         // Find all possible y-wing hinges and wings
         let cells_with_two_candidates: Vec<(usize, usize)> = iproduct!(0..9, 0..9)
@@ -98,19 +98,20 @@ impl SudokuSolveMethod for YWingSolver {
                 visualizer_updates.push(VisualizerUpdate::SetTitle("Y-Wing".to_string()));
 
                 for (row, col) in [hinge, wings[0], wings[1]] {
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_USED_TO_DETERMINE_SOLUTION));
+                    let role = if (row, col) == hinge { HighlightRole::Pivot } else { HighlightRole::Pincer };
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, role));
                     for &candidate in &sgrid.candidates[row][col] {
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, Colors::DIGIT_USED_TO_DETERMINE_SOLUTION));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, candidate, HighlightRole::Pivot));
                     }
                 }
 
                 for &cell in shared_cells {
                     if cell == hinge || cell == wings[0] || cell == wings[1] { continue; }
                     let (row, col) = cell;
-                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, Colors::CELL_MARKED_FOR_CANDIDATE_REMOVEAL));
+                    visualizer_updates.push(VisualizerUpdate::ColorCell(row, col, HighlightRole::Elimination));
                     if sgrid.candidates[row][col].contains(&c) {
                         reductions.push(SolverAction::CandidateReduction(row, col, c));
-                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, c, Colors::CANDIDATE_MARKED_FOR_REMOVAL));
+                        visualizer_updates.push(VisualizerUpdate::ColorCandidate(row, col, c, HighlightRole::Elimination));
                     }
                 }
                 if !reductions.is_empty() { 
@@ -125,11 +126,16 @@ impl SudokuSolveMethod for YWingSolver {
                             )
                         )
                     );                     
-                    return Some((reductions, visualizer_updates)); 
+                    return Ok(Some((reductions, visualizer_updates))); 
                 }
             }
         }
 
-        None
+        Ok(None)
+    }
+
+    // The hinge and both wings have exactly two candidates.
+    fn state_key(&self, sgrid: &SudokuGrid) -> u64 {
+        candidate_fingerprint(sgrid, |row, col| sgrid.candidates[row][col].len() == 2)
     }
 }
@@ -1,21 +1,71 @@
 use std::fmt;
 use itertools::{iproduct, Itertools};
+use rand::Rng;
 use rand::seq::SliceRandom;
 use std::collections::HashSet;
 use super::adjacency_graph::AdjacencyGraph;
+use super::grid_transform;
 
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct SudokuGrid {
     pub grid: [[usize; 9]; 9],
     pub candidates: [[HashSet<usize>; 9]; 9]
 }
-#[derive(PartialEq, Copy, Clone)]
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub enum UnitType {
     Row,
     Col,
     Box
 }
 
+// A reason the grid can no longer be completed, units are indexed 0-8.
+#[derive(PartialEq, Clone, Debug)]
+pub enum GridViolation {
+    // An unsolved cell has no candidates left.
+    EmptyCell(usize, usize),
+    // The digit is neither placed nor a candidate anywhere in the unit.
+    DigitMissingFromUnit { digit: usize, unit_type: UnitType, unit_index: usize, cells: Vec<(usize, usize)> },
+    // The digit is placed in more than one cell of the unit.
+    DuplicateDigit { digit: usize, unit_type: UnitType, unit_index: usize, cells: Vec<(usize, usize)> },
+}
+
+impl fmt::Display for GridViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridViolation::EmptyCell(row, col) => write!(f, "The cell {} has no candidates left.", SudokuGrid::cell_to_str((*row, *col))),
+            GridViolation::DigitMissingFromUnit { digit, unit_type, unit_index, .. } => {
+                write!(f, "The digit {} has no position left in {}.", digit, SudokuGrid::unit_to_str(*unit_type, *unit_index))
+            },
+            GridViolation::DuplicateDigit { digit, unit_type, unit_index, cells } => write!(
+                f,
+                "The digit {} is placed more than once in {} at [{}].",
+                digit,
+                SudokuGrid::unit_to_str(*unit_type, *unit_index),
+                cells.iter().map(|&cell| SudokuGrid::cell_to_str(cell)).collect::<Vec<_>>().join("; ")
+            ),
+        }
+    }
+}
+
+// A reason a grid string could not be read, cells are indexed 0-80.
+#[derive(PartialEq, Clone, Debug)]
+pub enum GridParseError {
+    // The string does not describe exactly 81 cells.
+    CellCount(usize),
+    InvalidCell(usize, String),
+}
+
+impl fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridParseError::CellCount(count) => write!(f, "Expected 81 cells but found {}.", count),
+            GridParseError::InvalidCell(index, cell) => write!(f, "Invalid cell \"{}\" at {}.", cell, SudokuGrid::cell_to_str((index / 9, index % 9))),
+        }
+    }
+}
+
+impl std::error::Error for GridParseError {}
+
 impl SudokuGrid {
     fn format_cell(&self, row: usize, col: usize, sub_row: usize) -> String {
         if self.grid[row][col] != 0 {
@@ -95,18 +145,28 @@ impl SudokuGrid {
     }
 
     pub fn from_string(init_str: &str) -> Self {
-        // Ensure the string has the correct length
-        assert_eq!(init_str.len(), 81, "Input string must have exactly 81 characters.");
+        match SudokuGrid::try_from_string(init_str) {
+            Ok(sgrid) => sgrid,
+            Err(error) => panic!("Invalid input string: {}", error),
+        }
+    }
+
+    // 81 characters, digits for givens and 0 or '.' for empty cells.
+    pub fn try_from_string(init_str: &str) -> Result<Self, GridParseError> {
+        let cells: Vec<char> = init_str.chars().collect();
+        if cells.len() != 81 {
+            return Err(GridParseError::CellCount(cells.len()));
+        }
 
         let mut grid = [[0 as usize; 9]; 9];
         let candidates: [[HashSet<usize>; 9]; 9] = Default::default();
 
-        for (i, ch) in init_str.chars().enumerate() {
+        for (i, &ch) in cells.iter().enumerate() {
             let row = i / 9;
             let col = i % 9;
-            match ch.to_digit(10) {
-                Some(val) => grid[row][col] = val as usize,
-                None => panic!("Invalid character in input string."),
+            match ch {
+                '.' => grid[row][col] = 0,
+                _ => grid[row][col] = ch.to_digit(10).ok_or_else(|| GridParseError::InvalidCell(i, ch.to_string()))? as usize,
             };
         }
 
@@ -115,7 +175,7 @@ impl SudokuGrid {
             candidates,
         };
         ret.regenerate_candidates();
-        ret
+        Ok(ret)
     }
 
     pub fn add_digit(&mut self, digit: usize, row: usize, col: usize) -> bool {
@@ -161,12 +221,17 @@ impl SudokuGrid {
     }
 
     pub fn create_sudoku_grid_randomly() -> SudokuGrid {
+        SudokuGrid::create_sudoku_grid_with_rng(&mut rand::thread_rng())
+    }
+
+    // The grid only depends on the values drawn from the rng, a seeded rng gives the same grid on every platform.
+    pub fn create_sudoku_grid_with_rng<R: Rng + ?Sized>(rng: &mut R) -> SudokuGrid {
         let mut sgrid = SudokuGrid::new();
         // Latin initialization
         // Generate random numbers for the three main box diagonals and backtrack to fill the rest
         let mut nums: Vec<usize> = (1..=9 as usize).collect();
         for i in (0..9).step_by(3) {
-            nums.shuffle(&mut rand::thread_rng()); // You'll need the `rand` crate for shuffling.
+            nums.shuffle(rng); // You'll need the `rand` crate for shuffling.
             for j in 0..3 {
                 for k in 0..3 {
                     sgrid.add_digit(nums[j * 3 + k], i + j, i + k);
@@ -180,7 +245,11 @@ impl SudokuGrid {
     }
 
     pub fn create_sudoku_puzzle(removals: usize) -> SudokuGrid {
-        let mut sgrid = SudokuGrid::create_sudoku_grid_randomly();
+        SudokuGrid::create_sudoku_puzzle_with_rng(removals, &mut rand::thread_rng())
+    }
+
+    pub fn create_sudoku_puzzle_with_rng<R: Rng + ?Sized>(removals: usize, rng: &mut R) -> SudokuGrid {
+        let mut sgrid = SudokuGrid::create_sudoku_grid_with_rng(rng);
         let mut iterations = removals;
         while iterations > 0 {
             let mut cells: Vec<(usize, usize)> = Vec::new();
@@ -191,7 +260,7 @@ impl SudokuGrid {
                     }
                 }
             }
-            cells.shuffle(&mut rand::thread_rng());
+            cells.shuffle(rng);
             let (row, col) = cells[0];
             let old_val = sgrid.grid[row][col];
             let old_candidates = sgrid.candidates.clone();
@@ -247,6 +316,8 @@ impl SudokuGrid {
             return cells - 3 // Will count itself 3 times
         };
         let mut ret : Vec<usize> = self.candidates[row][col].clone().into_iter().collect();
+        // Sort first so ties keep the same order, set iteration order differs between runs
+        ret.sort();
         // Least constraining value, pick value that limits the least
         ret.sort_by_key(|x| cells_affects(*x));
         return ret;
@@ -317,6 +388,61 @@ impl SudokuGrid {
             .join("")
     }
 
+    // Pencil mark position as 9 lines of 9 cells, placed digits are written as the digit
+    // and unsolved cells as their candidates in brackets, like [125].
+    pub fn to_candidate_string(&self) -> String {
+        let mut lines = Vec::new();
+        for row in 0..9 {
+            let cells: Vec<String> = (0..9).map(|col| {
+                let cell = if self.grid[row][col] != 0 {
+                    self.grid[row][col].to_string()
+                } else {
+                    format!("[{}]", self.candidates[row][col].iter().sorted().join(""))
+                };
+                format!("{:<11}", cell)
+            }).collect();
+            lines.push(cells.join(" ").trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+
+    // Reads the format written by to_candidate_string, any whitespace separates cells.
+    pub fn from_candidate_string(candidate_str: &str) -> Result<SudokuGrid, GridParseError> {
+        let cells: Vec<&str> = candidate_str.split_whitespace().collect();
+        if cells.len() != 81 {
+            return Err(GridParseError::CellCount(cells.len()));
+        }
+
+        let mut sgrid = SudokuGrid::new();
+        for (index, cell) in cells.iter().enumerate() {
+            let (row, col) = (index / 9, index % 9);
+            let invalid = || GridParseError::InvalidCell(index, cell.to_string());
+            let digits: Vec<usize> = if cell.starts_with('[') && cell.ends_with(']') && cell.len() >= 2 {
+                cell[1..cell.len() - 1].chars().map(|ch| ch.to_digit(10).filter(|&d| d != 0).map(|d| d as usize)).collect::<Option<_>>().ok_or_else(invalid)?
+            } else {
+                let digit = cell.parse::<usize>().ok().filter(|digit| (1..=9).contains(digit)).ok_or_else(invalid)?;
+                sgrid.grid[row][col] = digit;
+                vec![digit]
+            };
+            sgrid.candidates[row][col] = digits.into_iter().collect();
+        }
+        Ok(sgrid)
+    }
+
+    // Equivalent grids, under relabelling, line and band permutations and transposition, share the same minlex string.
+    pub fn minlex_string(&self) -> String {
+        grid_transform::canonical_form(self).0.to_number_string()
+    }
+
+    pub fn is_equivalent(&self, other: &SudokuGrid) -> bool {
+        self.minlex_string() == other.minlex_string()
+    }
+
+    // Number of cells with a digit placed.
+    pub fn clue_count(&self) -> usize {
+        self.grid.iter().flatten().filter(|&&digit| digit != 0).count()
+    }
+
     pub fn has_unique_solution(&self) -> bool {
         let mut solutions = 0;
         let mut a = self.clone();
@@ -324,6 +450,32 @@ impl SudokuGrid {
         solutions == 1
     }
 
+    // Number of solutions of the placed digits up to the limit, zero when a digit repeats in a unit.
+    pub fn count_solutions(grid: &[[usize; 9]; 9], limit: usize) -> usize {
+        SolutionCounter::new(grid).map_or(0, |mut counter| counter.count(limit))
+    }
+
+    // A puzzle is minimal when it has a unique solution and removing any given makes the solution ambiguous.
+    pub fn is_minimal(&self) -> bool {
+        // One search workspace is reused, each clue is taken out for its check and put back afterwards
+        let mut workspace = match SolutionCounter::new(&self.grid) {
+            Some(workspace) => workspace,
+            None => return false,
+        };
+        if workspace.count(2) != 1 { return false; }
+        for row in 0..9 {
+            for col in 0..9 {
+                let digit = self.grid[row][col];
+                if digit == 0 { continue; }
+                workspace.remove(row, col, digit);
+                let unique = workspace.count(2) == 1;
+                workspace.place(row, col, digit);
+                if unique { return false; }
+            }
+        }
+        true
+    }
+
     fn check_solutions(&mut self, solutions: &mut u32) {
         // If already found multiple solutions, exit early
         if *solutions > 1 {
@@ -548,4 +700,122 @@ impl SudokuGrid {
         format!("{},{}", SudokuGrid::cell_to_str((row, col)), candidate)
     }
 
-}
\ No newline at end of file
+    // Names units the same way cells are named, rows A-I and columns/boxes 1-9.
+    pub fn unit_to_str(unit_type: UnitType, index: usize) -> String {
+        match unit_type {
+            UnitType::Row => format!("row {}", (b'A' + index as u8) as char),
+            UnitType::Col => format!("column {}", index + 1),
+            UnitType::Box => format!("box {}", index + 1),
+        }
+    }
+
+    // Reports every reason the current digits and candidates can no longer form a solution.
+    pub fn validate(&self) -> Vec<GridViolation> {
+        let mut violations = Vec::new();
+
+        for row in 0..9 {
+            for col in 0..9 {
+                if self.grid[row][col] == 0 && self.candidates[row][col].is_empty() {
+                    violations.push(GridViolation::EmptyCell(row, col));
+                }
+            }
+        }
+
+        for unit_type in [UnitType::Row, UnitType::Col, UnitType::Box] {
+            for (unit_index, unit) in SudokuGrid::get_all_units_from_unit_type(unit_type).into_iter().enumerate() {
+                for digit in 1..=9 {
+                    let placed: Vec<(usize, usize)> = unit.iter().filter(|&&(row, col)| self.grid[row][col] == digit).cloned().collect();
+                    if placed.len() > 1 {
+                        violations.push(GridViolation::DuplicateDigit { digit, unit_type, unit_index, cells: placed });
+                        continue;
+                    }
+
+                    let has_position = !placed.is_empty() || unit.iter().any(|&(row, col)| self.grid[row][col] == 0 && self.candidates[row][col].contains(&digit));
+                    if !has_position {
+                        violations.push(GridViolation::DigitMissingFromUnit { digit, unit_type, unit_index, cells: unit.clone() });
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+}
+
+// Backtracking search over digit bitmasks of the rows, columns and boxes, much cheaper than copying candidate sets.
+// The grid is left as it was after each count.
+struct SolutionCounter {
+    grid: [[usize; 9]; 9],
+    rows: [u16; 9],
+    cols: [u16; 9],
+    boxes: [u16; 9],
+}
+
+impl SolutionCounter {
+    // None when a digit is placed twice in a unit.
+    fn new(grid: &[[usize; 9]; 9]) -> Option<Self> {
+        let mut counter = Self { grid: [[0; 9]; 9], rows: [0; 9], cols: [0; 9], boxes: [0; 9] };
+        for (row, col) in iproduct!(0..9, 0..9) {
+            let digit = grid[row][col];
+            if digit == 0 { continue; }
+            if counter.options(row, col) & 1 << digit == 0 { return None; }
+            counter.place(row, col, digit);
+        }
+        Some(counter)
+    }
+
+    fn options(&self, row: usize, col: usize) -> u16 {
+        !(self.rows[row] | self.cols[col] | self.boxes[3 * (row / 3) + col / 3]) & 0b11_1111_1110
+    }
+
+    fn place(&mut self, row: usize, col: usize, digit: usize) {
+        self.grid[row][col] = digit;
+        self.rows[row] |= 1 << digit;
+        self.cols[col] |= 1 << digit;
+        self.boxes[3 * (row / 3) + col / 3] |= 1 << digit;
+    }
+
+    fn remove(&mut self, row: usize, col: usize, digit: usize) {
+        self.grid[row][col] = 0;
+        self.rows[row] &= !(1 << digit);
+        self.cols[col] &= !(1 << digit);
+        self.boxes[3 * (row / 3) + col / 3] &= !(1 << digit);
+    }
+
+    // Number of solutions, counting stops once the limit is reached.
+    fn count(&mut self, limit: usize) -> usize {
+        // Most constrained cell first
+        let mut best: Option<(usize, usize, u16)> = None;
+        for (row, col) in iproduct!(0..9, 0..9) {
+            if self.grid[row][col] != 0 { continue; }
+            let options = self.options(row, col);
+            let fewer = match best {
+                Some((_, _, best_options)) => options.count_ones() < best_options.count_ones(),
+                None => true,
+            };
+            if fewer {
+                best = Some((row, col, options));
+                if options.count_ones() <= 1 { break; }
+            }
+        }
+        let (row, col, options) = match best {
+            Some(best) => best,
+            None => return 1,
+        };
+
+        let mut solutions = 0;
+        for digit in 1..=9 {
+            if options & 1 << digit == 0 { continue; }
+            self.place(row, col, digit);
+            solutions += self.count(limit - solutions);
+            self.remove(row, col, digit);
+            if solutions >= limit { break; }
+        }
+        solutions
+    }
+}
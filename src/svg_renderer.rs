@@ -0,0 +1,124 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::color::Color;
+use crate::grid_painter::{paint_grid, paint_header, Painter};
+use crate::layout::{GridLayout, Point};
+use crate::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use crate::theme::Theme;
+
+// Escapes text for use in XML and HTML content and attribute values.
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Renders visualizers as standalone SVG documents with the layout of the raylib window.
+pub struct SvgRenderer {
+    pub width: i32,
+    pub height: i32,
+    pub theme: Theme,
+    // Without the header only the grid is drawn, filling the whole image.
+    pub header: bool,
+}
+
+impl SvgRenderer {
+    pub fn new(width: i32, height: i32) -> Self {
+        Self {
+            width,
+            height,
+            theme: Theme::light(),
+            header: true,
+        }
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn set_header(&mut self, header: bool) {
+        self.header = header;
+    }
+
+    pub fn layout(&self) -> GridLayout {
+        if self.header {
+            GridLayout::below_header(self.width, self.height)
+        } else {
+            GridLayout::new(0.0, 0.0, self.width as f32, self.height as f32)
+        }
+    }
+
+    fn paint(attribute: &str, color: Color) -> String {
+        let mut paint = format!("{}=\"rgb({},{},{})\"", attribute, color.r, color.g, color.b);
+        if color.a != 255 {
+            paint.push_str(&format!(" {}-opacity=\"{:.3}\"", attribute, color.a as f32 / 255.0));
+        }
+        paint
+    }
+
+    // The svg elements of the grid alone, sorted so the output is stable.
+    pub fn render_grid(&self, builder: &SudokuVisualizerBuilder, layout: &GridLayout) -> String {
+        let mut painter = SvgPainter { svg: String::new() };
+        let obstacles: Vec<(usize, usize, usize)> = builder.candidates.keys().copied().collect();
+        paint_grid(&mut painter, layout, builder, &obstacles, &self.theme);
+        painter.svg
+    }
+
+    // A complete svg document with the title and description above the grid.
+    pub fn render(&self, builder: &SudokuVisualizerBuilder) -> String {
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\" font-family=\"sans-serif\" text-anchor=\"middle\" dominant-baseline=\"central\">",
+            self.width, self.height
+        ).unwrap();
+        writeln!(svg, "<rect width=\"100%\" height=\"100%\" {}/>", SvgRenderer::paint("fill", self.theme.background)).unwrap();
+        if self.header {
+            let mut painter = SvgPainter { svg: String::new() };
+            paint_header(&mut painter, builder, self.width, self.height, &self.theme);
+            svg.push_str(&painter.svg);
+        }
+        svg.push_str(&self.render_grid(builder, &self.layout()));
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn save(&self, builder: &SudokuVisualizerBuilder, path: &Path) -> io::Result<()> {
+        fs::write(path, self.render(builder))
+    }
+}
+
+struct SvgPainter {
+    svg: String,
+}
+
+impl Painter for SvgPainter {
+    fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        writeln!(self.svg, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {}/>", x, y, width, height, SvgRenderer::paint("fill", color)).unwrap();
+    }
+
+    fn line(&mut self, from: Point, to: Point, thickness: f32, color: Color) {
+        writeln!(
+            self.svg,
+            "<line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\" stroke-width=\"{}\" {}/>",
+            from.x, from.y, to.x, to.y, thickness, SvgRenderer::paint("stroke", color)
+        ).unwrap();
+    }
+
+    fn curve(&mut self, from: Point, control_point: Point, to: Point, thickness: f32, color: Color) {
+        writeln!(
+            self.svg,
+            "<path d=\"M {:.2} {:.2} Q {:.2} {:.2} {:.2} {:.2}\" fill=\"none\" stroke-width=\"{}\" {}/>",
+            from.x, from.y, control_point.x, control_point.y, to.x, to.y, thickness, SvgRenderer::paint("stroke", color)
+        ).unwrap();
+    }
+
+    fn text_centered(&mut self, text: &str, center: Point, size: i32, color: Color) {
+        writeln!(
+            self.svg,
+            "<text x=\"{:.2}\" y=\"{:.2}\" font-size=\"{}\" {}>{}</text>",
+            center.x, center.y, size, SvgRenderer::paint("fill", color), escape_xml(text)
+        ).unwrap();
+    }
+}
@@ -0,0 +1,183 @@
+use crate::color::Color;
+
+// What a highlighted digit, cell or chain means in a step, renderers pick the colour through a Theme.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, PartialOrd, Ord)]
+pub enum HighlightRole {
+    // Digits of the puzzle and candidates that take no part in the step.
+    Given,
+    Candidate,
+    // A digit placed by the step.
+    Solved,
+    // Cells and candidates the deduction is based on.
+    Pivot,
+    // The wings of a Y-Wing or XYZ-Wing, they see the eliminated candidates.
+    Pincer,
+    // Candidates removed by the step and the cells holding them.
+    Elimination,
+    // The two colours of a coloring or alternating chain.
+    ChainOn,
+    ChainOff,
+    // Links between candidates, of unknown, strong or weak type.
+    Link,
+    StrongLink,
+    WeakLink,
+    // Candidates ruled out while following an assumption.
+    Dimmed,
+    // The cell a player is editing and digits that break a rule of the puzzle, used by the play mode.
+    Selected,
+    Conflict,
+}
+
+// Maps roles to colours. The foreground is used for digits, candidates and chain lines, the fill for cell and candidate backgrounds.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Theme {
+    pub name: &'static str,
+    pub background: Color,
+    pub text: Color,
+    pub box_line: Color,
+    pub cell_line: Color,
+    pub given: Color,
+    pub candidate: Color,
+    pub solved: Color,
+    pub pivot_digit: Color,
+    pub pivot_cell: Color,
+    pub pincer_cell: Color,
+    pub elimination_digit: Color,
+    pub elimination_cell: Color,
+    pub chain_on: Color,
+    pub chain_off: Color,
+    pub link: Color,
+    pub strong_link: Color,
+    pub weak_link: Color,
+    pub dimmed: Color,
+    pub selected_cell: Color,
+    pub conflict_cell: Color,
+}
+
+impl Theme {
+    pub const NAMES: [&'static str; 3] = ["light", "dark", "colorblind"];
+
+    // The colours the visualizer has always used, with a lighter shade of the pivot cell added for the pincers.
+    pub fn light() -> Self {
+        Self {
+            name: "light",
+            background: Color::WHITE,
+            text: Color::BLACK,
+            box_line: Color::BLACK,
+            cell_line: Color::LIGHTGRAY,
+            given: Color::GRAY,
+            candidate: Color::BLACK,
+            solved: Color::new(12, 94, 34, 255),
+            pivot_digit: Color::new(204, 242, 15, 255),
+            pivot_cell: Color::new(196, 145, 69, 255),
+            pincer_cell: Color::new(222, 184, 135, 255),
+            elimination_digit: Color::new(230, 14, 68, 255),
+            elimination_cell: Color::new(175, 203, 204, 255),
+            chain_on: Color::new(237, 188, 97, 255),
+            chain_off: Color::new(110, 212, 210, 255),
+            link: Color::new(123, 126, 143, 255),
+            strong_link: Color::new(200, 70, 70, 255),
+            weak_link: Color::new(65, 212, 120, 255),
+            dimmed: Color::new(25, 25, 25, 100),
+            selected_cell: Color::new(187, 222, 251, 255),
+            conflict_cell: Color::new(255, 205, 210, 255),
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            name: "dark",
+            background: Color::new(30, 30, 36, 255),
+            text: Color::new(220, 220, 220, 255),
+            box_line: Color::new(200, 200, 200, 255),
+            cell_line: Color::new(80, 80, 90, 255),
+            given: Color::new(150, 150, 160, 255),
+            candidate: Color::new(220, 220, 220, 255),
+            solved: Color::new(120, 220, 140, 255),
+            pivot_digit: Color::new(250, 230, 90, 255),
+            pivot_cell: Color::new(110, 80, 30, 255),
+            pincer_cell: Color::new(90, 70, 110, 255),
+            elimination_digit: Color::new(255, 90, 110, 255),
+            elimination_cell: Color::new(40, 70, 80, 255),
+            chain_on: Color::new(150, 110, 40, 255),
+            chain_off: Color::new(30, 110, 110, 255),
+            link: Color::new(150, 150, 170, 255),
+            strong_link: Color::new(230, 100, 100, 255),
+            weak_link: Color::new(90, 220, 140, 255),
+            dimmed: Color::new(200, 200, 200, 80),
+            selected_cell: Color::new(50, 70, 110, 255),
+            conflict_cell: Color::new(110, 40, 50, 255),
+        }
+    }
+
+    // Built from the Okabe-Ito palette, the pairs that must be told apart differ in more than red and green.
+    pub fn colorblind() -> Self {
+        Self {
+            name: "colorblind",
+            background: Color::WHITE,
+            text: Color::BLACK,
+            box_line: Color::BLACK,
+            cell_line: Color::LIGHTGRAY,
+            given: Color::GRAY,
+            candidate: Color::BLACK,
+            solved: Color::new(0, 114, 178, 255),
+            pivot_digit: Color::new(0, 114, 178, 255),
+            pivot_cell: Color::new(240, 200, 120, 255),
+            pincer_cell: Color::new(235, 190, 215, 255),
+            elimination_digit: Color::new(213, 94, 0, 255),
+            elimination_cell: Color::new(190, 225, 245, 255),
+            chain_on: Color::new(230, 159, 0, 255),
+            chain_off: Color::new(86, 180, 233, 255),
+            link: Color::new(120, 120, 120, 255),
+            strong_link: Color::new(213, 94, 0, 255),
+            weak_link: Color::new(0, 158, 115, 255),
+            dimmed: Color::new(25, 25, 25, 100),
+            selected_cell: Color::new(200, 225, 245, 255),
+            conflict_cell: Color::new(245, 200, 160, 255),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "light" => Some(Theme::light()),
+            "dark" => Some(Theme::dark()),
+            "colorblind" => Some(Theme::colorblind()),
+            _ => None,
+        }
+    }
+
+    pub fn foreground(&self, role: HighlightRole) -> Color {
+        match role {
+            HighlightRole::Given => self.given,
+            HighlightRole::Candidate => self.candidate,
+            HighlightRole::Solved => self.solved,
+            HighlightRole::Pivot | HighlightRole::Pincer => self.pivot_digit,
+            HighlightRole::Elimination | HighlightRole::Conflict => self.elimination_digit,
+            HighlightRole::Selected => self.text,
+            HighlightRole::ChainOn => self.chain_on,
+            HighlightRole::ChainOff => self.chain_off,
+            HighlightRole::Link => self.link,
+            HighlightRole::StrongLink => self.strong_link,
+            HighlightRole::WeakLink => self.weak_link,
+            HighlightRole::Dimmed => self.dimmed,
+        }
+    }
+
+    pub fn fill(&self, role: HighlightRole) -> Color {
+        match role {
+            HighlightRole::Given | HighlightRole::Candidate => self.background,
+            HighlightRole::Solved | HighlightRole::Pivot => self.pivot_cell,
+            HighlightRole::Pincer => self.pincer_cell,
+            HighlightRole::Elimination => self.elimination_cell,
+            HighlightRole::Selected => self.selected_cell,
+            HighlightRole::Conflict => self.conflict_cell,
+            role => self.foreground(role),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::light()
+    }
+}
@@ -0,0 +1,69 @@
+extern crate sudoku_generator;
+
+use std::time::Duration;
+
+use sudoku_generator::animation_exporter::AnimationExporter;
+use sudoku_generator::png::{self, Frame};
+use sudoku_generator::png_renderer::PngRenderer;
+use sudoku_generator::solvers::solver_manager::SudokuSolverManager;
+use sudoku_generator::sudoku_grid::SudokuGrid;
+
+// The kind and data of every chunk after the signature.
+fn chunks(encoded: &[u8]) -> Vec<(String, Vec<u8>)> {
+    let mut chunks = Vec::new();
+    let mut position = 8;
+    while position < encoded.len() {
+        let length = u32::from_be_bytes(encoded[position..position + 4].try_into().unwrap()) as usize;
+        let kind = String::from_utf8(encoded[position + 4..position + 8].to_vec()).unwrap();
+        chunks.push((kind, encoded[position + 8..position + 8 + length].to_vec()));
+        position += length + 12;
+    }
+    chunks
+}
+
+#[test]
+fn test_encode_apng_chunks() {
+    let frames: Vec<Frame> = [0u8, 128, 255].iter().map(|&value| Frame { rgb: vec![value; 2 * 2 * 3], delay_ms: 500 }).collect();
+    let encoded = png::encode_apng(2, 2, &frames, 0);
+    let chunks = chunks(&encoded);
+    let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+    assert_eq!(kinds, ["IHDR", "acTL", "fcTL", "IDAT", "fcTL", "fdAT", "fcTL", "fdAT", "IEND"]);
+    assert_eq!(chunks[1].1, [0, 0, 0, 3, 0, 0, 0, 0]);
+
+    // Frame control and frame data chunks are numbered in one sequence
+    let sequence: Vec<u32> = chunks.iter().filter(|(kind, _)| kind == "fcTL" || kind == "fdAT").map(|(_, data)| u32::from_be_bytes(data[..4].try_into().unwrap())).collect();
+    assert_eq!(sequence, [0, 1, 2, 3, 4]);
+    // Delay of 500/1000 seconds
+    assert_eq!(chunks[2].1[20..24], [1, 244, 3, 232]);
+}
+
+#[test]
+fn test_animation_frames() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut manager = SudokuSolverManager::new(sgrid);
+    manager.solve().unwrap();
+    let stages: usize = manager.visualizers_per_step.iter().map(Vec::len).sum();
+    let steps: usize = manager.visualizers_per_step.iter().map(|visualizers| visualizers.len() - 2).sum();
+
+    let mut exporter = AnimationExporter::new(PngRenderer::new(60, 60));
+    assert_eq!(exporter.frames(&manager.visualizers_per_step).len(), stages);
+    exporter.set_include_boards(false);
+    // Only the steps and the solved board at the end
+    let frames = exporter.frames(&manager.visualizers_per_step);
+    assert_eq!(frames.len(), steps + 1);
+    assert_eq!(frames.last().unwrap().title, manager.visualizers_per_step.last().unwrap().last().unwrap().title);
+
+    exporter.set_frame_delay(Duration::from_millis(200));
+    let encoded = exporter.render(&manager.visualizers_per_step);
+    assert_eq!(encoded[..8], png::SIGNATURE);
+    assert_eq!(chunks(&encoded).iter().filter(|(kind, _)| kind == "fcTL").count(), steps + 1);
+}
+
+#[test]
+fn test_render_without_stages() {
+    let exporter = AnimationExporter::new(PngRenderer::new(60, 60));
+    let encoded = exporter.render(&[]);
+    let chunks = chunks(&encoded);
+    assert_eq!(chunks[1].0, "acTL");
+    assert_eq!(chunks[1].1[..4], [0, 0, 0, 1]);
+}
@@ -0,0 +1,62 @@
+extern crate sudoku_generator;
+
+use sudoku_generator::ansi_renderer::AnsiRenderer;
+use sudoku_generator::solvers::x_wing_solver::XWingSolver;
+use sudoku_generator::solvers::solver_manager::SudokuSolverManager;
+use sudoku_generator::sudoku_grid::SudokuGrid;
+use sudoku_generator::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use sudoku_generator::theme::{HighlightRole, Theme};
+
+#[test]
+fn test_render_grid_without_color() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let text = AnsiRenderer::new(false).render_grid(&SudokuVisualizerBuilder::from_sudoku(&sgrid));
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 2 + 27 + 2);
+    assert!(!text.contains('\x1b'));
+    assert_eq!(lines[3], "A |..6   ...   ...  |  1   4.6     5  |...   ...   ...   ");
+}
+
+#[test]
+fn test_column_header_is_aligned() {
+    let sgrid = SudokuGrid::from_string("123456789000000000000000000000000000000000000000000000000000000000000000000000000");
+    let text = AnsiRenderer::new(false).render_grid(&SudokuVisualizerBuilder::from_sudoku(&sgrid));
+    let lines: Vec<&str> = text.lines().collect();
+
+    // The column numbers sit right above the digits of row A
+    for digit in '1'..='9' {
+        assert_eq!(lines[0].find(digit), lines[3].find(digit));
+    }
+}
+
+#[test]
+fn test_render_step_marks_eliminations() {
+    let xwing = SudokuGrid::from_string("093004560060003140004608309981345000347286951652070483406002890000400010029800034");
+    let mut manager = SudokuSolverManager::new(xwing);
+    manager.add_solver(Box::new(XWingSolver));
+    assert!(manager.solve_iteration().unwrap());
+
+    let text = AnsiRenderer::new(true).render_step(&manager.visualizers_per_step[0][1]);
+    assert!(text.contains("X-Wing - Step 1"));
+    // The light theme keeps the colours the raylib window always used
+    assert!(text.contains("\x1b[1;38;2;230;14;68m"));
+    assert!(text.contains("Chain "));
+}
+
+#[test]
+fn test_render_step_uses_theme() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut builder = SudokuVisualizerBuilder::from_sudoku(&sgrid);
+    builder.color_candidate(0, 0, 6, HighlightRole::Elimination);
+    builder.color_cell(0, 0, HighlightRole::Elimination);
+
+    let mut renderer = AnsiRenderer::new(true);
+    renderer.set_theme(Theme::from_name("dark").unwrap());
+    let text = renderer.render_grid(&builder);
+    let dark = Theme::dark();
+    let elimination = dark.foreground(HighlightRole::Elimination);
+    let cell = dark.fill(HighlightRole::Elimination);
+    assert!(text.contains(&format!("\x1b[1;38;2;{};{};{}m\x1b[48;2;{};{};{}m6", elimination.r, elimination.g, elimination.b, cell.r, cell.g, cell.b)));
+    assert!(!text.contains("230;14;68"));
+}
@@ -0,0 +1,128 @@
+extern crate sudoku_generator;
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use sudoku_generator::sudoku_grid::SudokuGrid;
+
+fn run_sudoku(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_sudoku")).args(args).output().expect("Unable to run the sudoku binary");
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+fn run_sudoku_with_input(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sudoku")).args(args).stdin(Stdio::piped()).stdout(Stdio::piped()).spawn().expect("Unable to run the sudoku binary");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_generate_csv() {
+    let (success, output) = run_sudoku(&["generate", "--count", "3", "--seed", "5", "--symmetry", "rotational180", "--clues", "28-32"]);
+    assert!(success);
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "puzzle,solution,rating,hardest_technique,seed");
+    assert_eq!(lines.len(), 4);
+    for line in &lines[1..] {
+        let fields: Vec<&str> = line.split(',').collect();
+        let puzzle = SudokuGrid::from_string(fields[0]);
+        assert!(puzzle.has_unique_solution());
+        assert!((28..=32).contains(&puzzle.clue_count()));
+        assert_eq!(fields.len(), 5);
+    }
+}
+
+#[test]
+fn test_generate_does_not_depend_on_threads() {
+    let (_, single) = run_sudoku(&["generate", "--count", "4", "--seed", "9", "--format", "jsonl", "--threads", "1"]);
+    let (_, parallel) = run_sudoku(&["generate", "--count", "4", "--seed", "9", "--format", "jsonl", "--threads", "3"]);
+    assert_eq!(single, parallel);
+    assert!(single.lines().all(|line| line.starts_with("{\"puzzle\":\"") && line.ends_with('}')));
+}
+
+#[test]
+fn test_generate_rejects_unknown_option_value() {
+    let (success, _) = run_sudoku(&["generate", "--symmetry", "spiral"]);
+    assert!(!success);
+}
+
+#[test]
+fn test_solve_json() {
+    let input = "# comment\n000105000140000670080002400063070010900000003010090520007200080026000035000409000\n093004560060003140004608309981345000347286951652070483406002890000400010029800034\n12345\n";
+    let output = run_sudoku_with_input(&["solve", "--format", "json", "--profile", "medium"], input);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("{\"line\":2,"));
+    assert!(lines[0].contains("\"solution\":\"672145398145983672389762451263574819958621743714398526597236184426817935831459267\""));
+    assert!(lines[0].contains("\"solved_by_techniques\":true"));
+    // The X-Wing puzzle also needs an X-Chain, which the medium profile does not have
+    assert!(lines[1].contains("\"solved_by_techniques\":false"));
+    assert!(lines[2].contains("\"error\":\"Expected 81 cells but found 5.\""));
+}
+
+#[test]
+fn test_solve_reads_generated_csv() {
+    let (_, generated) = run_sudoku(&["generate", "--count", "2", "--seed", "3"]);
+    let output = run_sudoku_with_input(&["solve"], &generated);
+    assert_eq!(output.matches("Solution: ").count(), 2);
+}
+
+#[test]
+fn test_explain_prints_every_step() {
+    let (success, output) = run_sudoku(&["explain", "--puzzle", "000105000140000670080002400063070010900000003010090520007200080026000035000409000", "--color", "never"]);
+    assert!(success);
+    assert!(output.contains("Step 1"));
+    assert!(output.contains("Solved."));
+    assert!(!output.contains('\x1b'));
+}
+
+#[test]
+fn test_export_writes_every_stage() {
+    let directory = std::env::temp_dir().join(format!("sudoku_export_{}", std::process::id()));
+    let (success, output) = run_sudoku(&["export", "--puzzle", "000105000140000670080002400063070010900000003010090520007200080026000035000409000", "--output", directory.to_str().unwrap()]);
+    assert!(success);
+
+    let mut files: Vec<String> = std::fs::read_dir(&directory).unwrap().map(|entry| entry.unwrap().file_name().into_string().unwrap()).collect();
+    files.sort();
+    assert_eq!(output.trim(), format!("Wrote {} images to {}.", files.len(), directory.display()));
+    assert_eq!(files[0], "step-001-00.svg");
+    assert!(std::fs::read_to_string(directory.join(&files[1])).unwrap().contains("Step 1"));
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn test_export_png() {
+    let directory = std::env::temp_dir().join(format!("sudoku_export_png_{}", std::process::id()));
+    let (success, _) = run_sudoku(&["export", "--format", "png", "--width", "200", "--height", "200", "--puzzle", "000105000140000670080002400063070010900000003010090520007200080026000035000409000", "--output", directory.to_str().unwrap()]);
+    assert!(success);
+
+    let image = std::fs::read(directory.join("step-001-00.png")).unwrap();
+    assert_eq!(image[..8], [137, 80, 78, 71, 13, 10, 26, 10]);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn test_animate() {
+    let path = std::env::temp_dir().join(format!("sudoku_animate_{}.png", std::process::id()));
+    let (success, output) = run_sudoku(&["animate", "--stages", "steps", "--width", "100", "--height", "100", "--puzzle", "000105000140000670080002400063070010900000003010090520007200080026000035000409000", "--output", path.to_str().unwrap()]);
+    assert!(success);
+    assert!(output.starts_with("Wrote "));
+
+    let image = std::fs::read(&path).unwrap();
+    assert_eq!(&image[37..41], b"acTL");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_image_size_must_be_positive() {
+    for size in ["0", "-100"] {
+        let (success, _) = run_sudoku(&["animate", "--width", size, "--puzzle", "000105000140000670080002400063070010900000003010090520007200080026000035000409000", "--output", "unused.png"]);
+        assert!(!success);
+        let (success, _) = run_sudoku(&["export", "--format", "png", "--height", size, "--puzzle", "000105000140000670080002400063070010900000003010090520007200080026000035000409000", "--output", "unused"]);
+        assert!(!success);
+    }
+}
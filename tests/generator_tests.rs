@@ -0,0 +1,196 @@
+extern crate sudoku_generator;
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use sudoku_generator::generators::full_grid_generator::FullGridGenerator;
+use sudoku_generator::generators::pattern_generator::{CluePattern, PatternError, PatternGenerator};
+use sudoku_generator::generators::puzzle_generator::PuzzleGenerator;
+use sudoku_generator::generators::showcase_generator::{ShowcaseError, ShowcaseGenerator};
+use sudoku_generator::generators::symmetry::Symmetry;
+use sudoku_generator::generators::targeted_generator::TargetedGenerator;
+use sudoku_generator::solvers::difficulty::{solvers_up_to, Difficulty};
+use sudoku_generator::solvers::sudoku_solver::SudokuSolveMethod;
+use sudoku_generator::solvers::x_wing_solver::XWingSolver;
+use sudoku_generator::sudoku_grid::{SudokuGrid, UnitType};
+
+#[test]
+fn test_symmetry_orbits_partition_grid() {
+    for symmetry in Symmetry::ALL {
+        let cells: usize = symmetry.orbits().iter().map(|orbit| orbit.len()).sum();
+        assert_eq!(cells, 81);
+    }
+    assert_eq!(Symmetry::Rotational90.orbit((4, 4)), vec![(4, 4)]);
+    assert_eq!(Symmetry::Rotational90.orbit((0, 1)), vec![(0, 1), (1, 8), (7, 0), (8, 7)]);
+}
+
+#[test]
+fn test_generate_rotational_symmetry() {
+    for symmetry in [Symmetry::Rotational180, Symmetry::Rotational90] {
+        let mut generator = PuzzleGenerator::new();
+        generator.set_symmetry(symmetry);
+        let sgrid = generator.generate_with_seed(1).puzzle;
+        assert!(sgrid.has_unique_solution());
+        assert!(symmetry.matches(&sgrid));
+    }
+}
+
+#[test]
+fn test_generate_mirror_symmetry() {
+    let mut generator = PuzzleGenerator::new();
+    generator.set_symmetry(Symmetry::VerticalMirror);
+    let sgrid = generator.generate_with_seed(2).puzzle;
+    assert!(sgrid.has_unique_solution());
+    assert!(Symmetry::VerticalMirror.matches(&sgrid));
+    assert!(sgrid.grid.iter().flatten().any(|&digit| digit == 0));
+}
+
+#[test]
+fn test_generate_difficulty_tier() {
+    let mut generator = TargetedGenerator::for_difficulty(Difficulty::Easy);
+    generator.set_max_attempts(200);
+    let (generated, summary) = generator.generate_with_seed(3).expect("An easy puzzle should be found");
+    let sgrid = generated.puzzle;
+
+    assert!(summary.solved);
+    assert_eq!(summary.difficulty(), Some(Difficulty::Easy));
+    assert!(generator.check(&sgrid).is_some());
+    assert!(TargetedGenerator::for_difficulty(Difficulty::Beginner).check(&sgrid).is_none());
+}
+
+#[test]
+fn test_check_must_use_technique() {
+    let mut generator = TargetedGenerator::new();
+    generator.set_must_use(vec!["XWingSolver"]);
+
+    let xwing = SudokuGrid::from_string("093004560060003140004608309981345000347286951652070483406002890000400010029800034");
+    let summary = generator.check(&xwing).expect("Puzzle should need an X-Wing");
+    assert!(summary.techniques_used.contains(&"XWingSolver"));
+    // The puzzle also needs an X-Chain, which is harder than X-Wing practice allows
+    assert!(TargetedGenerator::for_technique("XWingSolver").check(&xwing).is_none());
+
+    let simplest_sudoku = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    assert!(generator.check(&simplest_sudoku).is_none());
+}
+
+#[test]
+fn test_generate_minimal() {
+    let mut generator = PuzzleGenerator::new();
+    generator.set_removals(10);
+    generator.set_minimal(true);
+    let sgrid = generator.generate_with_seed(4).puzzle;
+    assert!(sgrid.is_minimal());
+}
+
+#[test]
+fn test_generate_minimal_with_min_clues() {
+    let mut generator = PuzzleGenerator::new();
+    generator.set_minimal(true);
+    generator.set_min_clues(26);
+    let sgrid = generator.generate_with_seed(7).puzzle;
+    assert!(sgrid.clue_count() >= 26);
+    assert!(sgrid.is_minimal());
+}
+
+#[test]
+fn test_generate_with_seed_is_reproducible() {
+    let mut generator = PuzzleGenerator::new();
+    generator.set_symmetry(Symmetry::Rotational180);
+    let first = generator.generate_with_seed(42);
+    let second = generator.generate_with_seed(42);
+
+    assert_eq!(first.seed, 42);
+    assert_eq!(first.puzzle.to_number_string(), second.puzzle.to_number_string());
+    assert_eq!(first.solution.to_number_string(), second.solution.to_number_string());
+    assert_ne!(first.puzzle.to_number_string(), generator.generate_with_seed(43).puzzle.to_number_string());
+}
+
+#[test]
+fn test_showcase_position_needs_technique() {
+    let xwing = SudokuGrid::from_string("093004560060003140004608309981345000347286951652070483406002890000400010029800034");
+    let (position, step, visualizer) = ShowcaseGenerator::new("XWingSolver").find_in(&xwing).unwrap().expect("Puzzle should need an X-Wing");
+
+    assert!(step >= 1);
+    assert!(visualizer.title.contains("X-Wing"));
+    // Only easier tiers run before the X-Wing
+    for solver in solvers_up_to(Difficulty::Easy) {
+        assert!(solver.apply(&position).unwrap().is_none(), "{} should not apply before the X-Wing", solver.name());
+    }
+    assert!(XWingSolver.apply(&position).unwrap().is_some());
+
+    let exported = SudokuGrid::from_candidate_string(&position.to_candidate_string()).unwrap();
+    assert_eq!(exported, position);
+}
+
+#[test]
+fn test_generate_showcase() {
+    let mut generator = ShowcaseGenerator::new("NakedCandidatesSolver<2>");
+    generator.set_max_attempts(200);
+    let showcase = generator.generate_with_seed(7).expect("A naked pair should be found");
+
+    assert!(showcase.position.is_consistent());
+    assert!(showcase.position.clue_count() >= showcase.generated.puzzle.clue_count());
+}
+
+#[test]
+fn test_showcase_unknown_technique() {
+    let generator = ShowcaseGenerator::new("NoSuchSolver");
+    assert!(matches!(generator.generate_with_seed(7), Err(ShowcaseError::UnknownTechnique(name)) if name == "NoSuchSolver"));
+}
+
+#[test]
+fn test_full_grid_generator() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let mut first_digits = HashSet::new();
+    for _ in 0..50 {
+        let sgrid = FullGridGenerator::generate(&mut rng);
+        assert!(sgrid.grid.iter().flatten().all(|&digit| digit != 0));
+        assert!(sgrid.validate().is_empty());
+        first_digits.insert(sgrid.grid[0][0]);
+    }
+    assert_eq!(first_digits.len(), 9);
+
+    let digits = FullGridGenerator::generate_digits(&mut ChaCha8Rng::seed_from_u64(3));
+    assert_eq!(digits, FullGridGenerator::generate_digits(&mut ChaCha8Rng::seed_from_u64(3)));
+}
+
+const DIAMOND_PATTERN: &str = "
+    x..x.x..x
+    xx....xx.
+    .x...xx..
+    .xx.x..x.
+    x.x...x.x
+    .x..x.xx.
+    ..xx...x.
+    .xx....xx
+    x..x.x..x";
+
+#[test]
+fn test_pattern_generator_fills_pattern() {
+    let pattern = CluePattern::from_string(DIAMOND_PATTERN).unwrap();
+    let generated = PatternGenerator::new(pattern).generate_with_seed(1).unwrap();
+
+    assert_eq!(generated.puzzle.clue_count(), pattern.clue_count());
+    for row in 0..9 {
+        for col in 0..9 {
+            assert_eq!(generated.puzzle.grid[row][col] != 0, pattern.contains((row, col)));
+        }
+    }
+    assert!(generated.puzzle.has_unique_solution());
+}
+
+#[test]
+fn test_pattern_generator_reports_unsatisfiable() {
+    let sixteen_clues: Vec<(usize, usize)> = (0..16).map(|i| (i / 2, (i * 4) % 9)).collect();
+    assert_eq!(PatternGenerator::new(CluePattern::from_cells(&sixteen_clues).unwrap()).generate().unwrap_err(), PatternError::TooFewClues(16));
+    assert_eq!(CluePattern::from_cells(&[(0, 0), (4, 9)]).unwrap_err(), PatternError::CellOutOfRange(4, 9));
+
+    let empty_rows = CluePattern::from_string(&DIAMOND_PATTERN.replacen("x..x.x..x", ".........", 1).replacen("xx....xx.", ".........", 1)).unwrap();
+    assert_eq!(PatternGenerator::new(empty_rows).generate().unwrap_err(), PatternError::InterchangeableLines(UnitType::Row, 0, 1));
+
+    let mut generator = PatternGenerator::new(CluePattern::from_string(DIAMOND_PATTERN).unwrap());
+    generator.set_time_budget(Duration::ZERO);
+    assert_eq!(generator.generate().unwrap_err(), PatternError::TimeBudgetExceeded { attempts: 0 });
+}
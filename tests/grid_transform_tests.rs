@@ -0,0 +1,105 @@
+extern crate sudoku_generator;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use sudoku_generator::generators::puzzle_generator::PuzzleGenerator;
+use sudoku_generator::grid_transform::{canonical_form, GridTransform};
+use sudoku_generator::solvers::difficulty::default_solvers;
+use sudoku_generator::solvers::solver_manager::{SolveSummary, SudokuSolverManager};
+use sudoku_generator::sudoku_grid::SudokuGrid;
+
+fn relabelled_copy(sgrid: &SudokuGrid) -> SudokuGrid {
+    let transform = GridTransform {
+        transpose: true,
+        row_order: [5, 3, 4, 8, 7, 6, 1, 0, 2],
+        col_order: [2, 0, 1, 6, 8, 7, 4, 3, 5],
+        digit_map: [0, 4, 9, 1, 7, 2, 6, 5, 3, 8],
+    };
+    transform.apply(sgrid)
+}
+
+fn rate(sgrid: &SudokuGrid) -> SolveSummary {
+    let mut manager = SudokuSolverManager::new(sgrid.clone());
+    for (_, solver) in default_solvers() {
+        manager.add_solver(solver);
+    }
+    manager.solve().unwrap()
+}
+
+#[test]
+fn test_line_orders_keep_bands() {
+    let orders = GridTransform::line_orders();
+    assert_eq!(orders.len(), 1296);
+    for order in orders {
+        for band in 0..3 {
+            assert!((0..3).all(|i| order[band * 3 + i] / 3 == order[band * 3] / 3));
+        }
+    }
+}
+
+#[test]
+fn test_canonical_form_of_solution_grid() {
+    let solution = SudokuGrid::from_string("435269781682571493197834562826195347374682915951743628519326874248957136763418259");
+    let (canonical, transform) = canonical_form(&solution);
+
+    assert!(canonical.to_number_string().starts_with("123456789"));
+    assert_eq!(transform.apply(&solution), canonical);
+    assert!(canonical.is_consistent());
+}
+
+#[test]
+fn test_equivalent_puzzles_share_minlex() {
+    let puzzle = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let disguised = relabelled_copy(&puzzle);
+
+    assert_ne!(puzzle.to_number_string(), disguised.to_number_string());
+    assert_eq!(puzzle.minlex_string(), disguised.minlex_string());
+    assert!(puzzle.is_equivalent(&disguised));
+
+    let other = SudokuGrid::from_string("720096003000205000080004020000000060106503807040000000030800090000702000200430018");
+    assert!(!puzzle.is_equivalent(&other));
+}
+
+#[test]
+fn test_rotate_quarter_turn() {
+    let puzzle = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let rotated = GridTransform::rotate(1).apply(&puzzle);
+
+    // The first column read bottom up becomes the first row
+    assert_eq!(rotated.grid[0], [0, 0, 0, 0, 9, 0, 0, 1, 0]);
+    assert_eq!(GridTransform::rotate(1).then(&GridTransform::rotate(1)), GridTransform::rotate(2));
+    assert_eq!(GridTransform::rotate(3).apply(&rotated), puzzle);
+}
+
+#[test]
+fn test_random_transform_round_trip() {
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    let generated = PuzzleGenerator::new().generate_with_seed(5);
+    for _ in 0..10 {
+        let transform = GridTransform::random(&mut rng);
+        assert!(transform.is_valid());
+        assert_eq!(transform.then(&transform.inverse()), GridTransform::identity());
+
+        let disguised = generated.transformed(&transform);
+        assert!(disguised.solution.is_consistent());
+        assert!(disguised.puzzle.has_unique_solution());
+        assert_eq!(transform.inverse().apply(&disguised.puzzle), generated.puzzle);
+    }
+}
+
+#[test]
+fn test_transform_keeps_rating() {
+    let mut rng = ChaCha8Rng::seed_from_u64(11);
+    for puzzle in [
+        "300000000970010000600583000200000900500621003008000005000435002000090056000000001",
+        "093004560060003140004608309981345000347286951652070483406002890000400010029800034",
+    ] {
+        let puzzle = SudokuGrid::from_string(puzzle);
+        let rating = rate(&puzzle);
+        for _ in 0..3 {
+            let disguised_rating = rate(&GridTransform::random(&mut rng).apply(&puzzle));
+            assert!(disguised_rating.solved);
+            assert_eq!(disguised_rating.difficulty(), rating.difficulty());
+        }
+    }
+}
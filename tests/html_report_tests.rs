@@ -0,0 +1,27 @@
+extern crate sudoku_generator;
+
+use sudoku_generator::html_report::HtmlReport;
+use sudoku_generator::solvers::difficulty::default_solvers;
+use sudoku_generator::solvers::solver_manager::SudokuSolverManager;
+use sudoku_generator::sudoku_grid::SudokuGrid;
+
+#[test]
+fn test_report_contains_every_stage() {
+    let puzzle = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut manager = SudokuSolverManager::new(puzzle.clone());
+    for (_, solver) in default_solvers() {
+        manager.add_solver(solver);
+    }
+    let summary = manager.solve().unwrap();
+
+    let html = HtmlReport::new().render(&puzzle, &manager, &summary);
+    let stages: usize = manager.visualizers_per_step.iter().map(|visualizers| visualizers.len()).sum();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert_eq!(html.matches("<section class=\"stage\">").count(), stages);
+    assert_eq!(html.matches("<svg ").count(), stages);
+    assert!(html.contains(&format!("<tr><th>Solution</th><td>{}</td></tr>", manager.sgrid.to_number_string())));
+    assert!(html.contains(&format!("<tr><th>Rating</th><td>{}</td></tr>", summary.difficulty().unwrap())));
+    // Nothing is loaded from elsewhere
+    assert!(!html.contains("src="));
+    assert!(!html.contains("href="));
+}
@@ -0,0 +1,77 @@
+extern crate sudoku_generator;
+
+use sudoku_generator::play_session::PlaySession;
+use sudoku_generator::solvers::sudoku_solver::SolverError;
+use sudoku_generator::sudoku_grid::SudokuGrid;
+use sudoku_generator::theme::HighlightRole;
+
+const PUZZLE: &str = "000105000140000670080002400063070010900000003010090520007200080026000035000409000";
+
+#[test]
+fn test_entries_and_pencil_marks() {
+    let mut session = PlaySession::new(SudokuGrid::from_string(PUZZLE));
+    // A1 is empty, A4 holds a given
+    session.select(0, 3);
+    assert!(!session.enter_digit(2));
+    assert!(!session.toggle_pencil_mark(2));
+
+    session.select(0, 0);
+    assert!(session.toggle_pencil_mark(3));
+    assert!(session.toggle_pencil_mark(7));
+    assert!(session.toggle_pencil_mark(3));
+    assert_eq!(session.pencil_marks[0][0].iter().copied().collect::<Vec<_>>(), [7]);
+
+    assert!(session.enter_digit(7));
+    assert_eq!(session.digit(0, 0), 7);
+    assert!(session.pencil_marks[0][0].is_empty());
+    assert!(session.clear_cell());
+    assert_eq!(session.digit(0, 0), 0);
+}
+
+#[test]
+fn test_conflicts() {
+    let mut session = PlaySession::new(SudokuGrid::from_string(PUZZLE));
+    assert!(session.conflicts().is_empty());
+
+    // A 1 is already given in A4 and B1
+    session.select(0, 0);
+    session.enter_digit(1);
+    let conflicts = session.conflicts();
+    assert!(conflicts.contains(&(0, 0)) && conflicts.contains(&(0, 3)) && conflicts.contains(&(1, 0)));
+
+    let visualizer = session.visualizer();
+    assert_eq!(visualizer.digits[&(0, 0)], (1, HighlightRole::Conflict));
+    assert_eq!(visualizer.cell_highlights[&(0, 3)], HighlightRole::Conflict);
+    assert_eq!(visualizer.cell_highlights[&(0, 0)], HighlightRole::Selected);
+    assert!(matches!(session.hint(), Err(SolverError::InvalidState(_))));
+}
+
+#[test]
+fn test_hint_and_explanation() {
+    let sgrid = SudokuGrid::from_string(PUZZLE);
+    let mut session = PlaySession::new(sgrid.clone());
+    let hint = session.hint().unwrap().unwrap();
+    assert_eq!(hint.title, "Hint");
+    // The hint does not place any digits
+    assert_eq!(hint.digits.len(), sgrid.clue_count());
+    let explanation = session.explain().unwrap().unwrap();
+    assert!(explanation.title.ends_with("Step 1"));
+
+    // A digit that fits the rules but not the solution
+    let mut solution = sgrid.clone();
+    solution.backtrack_fill();
+    let mut entered = false;
+    'search: for (row, col) in (0..81).map(|index| (index / 9, index % 9)).filter(|&(row, col)| sgrid.grid[row][col] == 0) {
+        session.select(row, col);
+        for digit in (1..=9).filter(|&digit| digit != solution.grid[row][col]) {
+            session.enter_digit(digit);
+            if session.conflicts().is_empty() {
+                entered = true;
+                break 'search;
+            }
+        }
+        session.clear_cell();
+    }
+    assert!(entered);
+    assert!(matches!(session.hint(), Err(SolverError::NoSolution)));
+}
@@ -0,0 +1,53 @@
+extern crate sudoku_generator;
+
+use sudoku_generator::png::{self, adler32, crc32};
+use sudoku_generator::png_renderer::PngRenderer;
+use sudoku_generator::sudoku_grid::SudokuGrid;
+use sudoku_generator::sudoku_visualizer_builder::SudokuVisualizerBuilder;
+use sudoku_generator::theme::{HighlightRole, Theme};
+
+#[test]
+fn test_checksums() {
+    assert_eq!(crc32(b"123456789"), 0xCBF43926);
+    assert_eq!(crc32(b"IEND"), 0xAE426082);
+    assert_eq!(adler32(b"Wikipedia"), 0x11E60398);
+}
+
+#[test]
+fn test_encode_png_chunks() {
+    let rgb = [255u8, 0, 0].repeat(4 * 3);
+    let encoded = png::encode_png(4, 3, &rgb);
+    assert_eq!(encoded[..8], png::SIGNATURE);
+    assert_eq!(&encoded[12..16], b"IHDR");
+    assert_eq!(encoded[16..24], [0, 0, 0, 4, 0, 0, 0, 3]);
+    assert_eq!(encoded[encoded.len() - 12..], [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]);
+    // Zlib header of a deflate stream with a 32 KiB window
+    assert_eq!(encoded[41..43], [0x78, 0x01]);
+}
+
+#[test]
+fn test_render_canvas() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut builder = SudokuVisualizerBuilder::from_sudoku(&sgrid);
+    builder.color_cell(4, 4, HighlightRole::Elimination);
+
+    let renderer = PngRenderer::new(400, 300);
+    let canvas = renderer.render_canvas(&builder);
+    assert_eq!((canvas.width, canvas.height), (400, 300));
+    let theme = Theme::light();
+    assert_eq!(canvas.pixel(0, 299), theme.background);
+
+    // The corner of a highlighted cell is clear of digits and candidates
+    let corner = renderer.layout().cell_corner(4, 4);
+    assert_eq!(canvas.pixel(corner.x as usize + 2, corner.y as usize + 2), theme.elimination_cell);
+    let line = renderer.layout().grid_lines(3).0;
+    assert_eq!(canvas.pixel(line.0.x as usize + 5, line.0.y as usize), theme.box_line);
+
+    assert_eq!(renderer.render(&builder), renderer.render(&builder));
+}
+
+#[test]
+#[should_panic(expected = "at least 1x1")]
+fn test_zero_size_is_rejected() {
+    PngRenderer::new(0, 300);
+}
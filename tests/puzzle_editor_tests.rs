@@ -0,0 +1,56 @@
+extern crate sudoku_generator;
+
+use sudoku_generator::puzzle_editor::{EditorError, PuzzleEditor};
+use sudoku_generator::sudoku_grid::SudokuGrid;
+use sudoku_generator::theme::HighlightRole;
+
+const PUZZLE: &str = "000105000140000670080002400063070010900000003010090520007200080026000035000409000";
+
+#[test]
+fn test_parse_puzzle() {
+    let expected = SudokuGrid::from_string(PUZZLE);
+    assert_eq!(PuzzleEditor::parse_puzzle(&format!("{}\n", PUZZLE)).unwrap(), expected);
+
+    // Nine lines of nine cells, as copied from a grid
+    let lines: Vec<&str> = (0..9).map(|row| &PUZZLE[row * 9..row * 9 + 9]).collect();
+    assert_eq!(PuzzleEditor::parse_puzzle(&lines.join("\n")).unwrap(), expected);
+
+    // The output of the generate command
+    let csv = format!("puzzle,solution,rating,hardest_technique\n{},123,Beginner,Hidden Single\n", PUZZLE);
+    assert_eq!(PuzzleEditor::parse_puzzle(&csv).unwrap(), expected);
+    assert!(PuzzleEditor::parse_puzzle("12345").is_err());
+}
+
+#[test]
+fn test_edit_and_finish() {
+    let mut editor = PuzzleEditor::new();
+    assert!(matches!(editor.load_string("not a puzzle"), Err(EditorError::Parse(_))));
+    assert_eq!(editor.grid, [[0; 9]; 9]);
+    assert!(matches!(editor.finish(), Err(EditorError::NotUnique)));
+
+    editor.load_string(PUZZLE).unwrap();
+    assert_eq!(editor.finish().unwrap(), SudokuGrid::from_string(PUZZLE));
+
+    // A second 1 in the first row
+    editor.select(0, 0);
+    assert!(editor.enter_digit(1));
+    assert!(matches!(editor.finish(), Err(EditorError::Conflict)));
+    let visualizer = editor.visualizer();
+    assert_eq!(visualizer.digits[&(0, 3)], (1, HighlightRole::Conflict));
+    assert_eq!(visualizer.cell_highlights[&(0, 0)], HighlightRole::Selected);
+    assert_eq!(visualizer.title, "Editor - 29 clues");
+
+    assert!(editor.enter_digit(0));
+    assert!(editor.finish().is_ok());
+}
+
+#[test]
+fn test_load_file() {
+    let path = std::env::temp_dir().join(format!("sudoku_editor_{}.txt", std::process::id()));
+    std::fs::write(&path, format!("# A puzzle\n{}\n", PUZZLE)).unwrap();
+    let mut editor = PuzzleEditor::new();
+    editor.load_file(&path).unwrap();
+    assert_eq!(editor.to_grid(), SudokuGrid::from_string(PUZZLE));
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(editor.load_file(&path), Err(EditorError::Read(_, _))));
+}
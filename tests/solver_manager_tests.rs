@@ -0,0 +1,260 @@
+extern crate sudoku_generator;
+
+use sudoku_generator::sudoku_grid::*;
+use sudoku_generator::solvers::solver_manager::{SudokuSolverManager, SolveMode};
+use sudoku_generator::solvers::naked_singles_solver::HiddenSinglesSolver;
+use sudoku_generator::solvers::difficulty::{default_solvers, Difficulty};
+use sudoku_generator::solvers::sudoku_solver::*;
+
+fn hidden_singles_manager(sgrid: SudokuGrid, solve_mode: SolveMode) -> SudokuSolverManager {
+    let mut solver = SudokuSolverManager::new(sgrid);
+    solver.add_solver(Box::new(HiddenSinglesSolver));
+    solver.set_solve_mode(solve_mode);
+    solver
+}
+
+fn count_digits(sgrid: &SudokuGrid) -> usize {
+    sgrid.grid.iter().flatten().filter(|&&digit| digit != 0).count()
+}
+
+#[test]
+fn test_all_instances_logs_each_instance() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut solver = hidden_singles_manager(sgrid.clone(), SolveMode::AllInstances);
+    assert!(solver.solve_iteration().unwrap());
+
+    let placed = count_digits(&solver.sgrid) - count_digits(&sgrid);
+    assert!(placed > 1);
+    // Pre step, one visualizer per placement and the post step
+    assert_eq!(solver.visualizers_per_step[0].len(), placed + 2);
+}
+
+#[test]
+fn test_all_instances_takes_fewer_steps() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+
+    let mut first_instance = hidden_singles_manager(sgrid.clone(), SolveMode::FirstInstance);
+    while first_instance.solve_iteration().unwrap() {}
+    let mut all_instances = hidden_singles_manager(sgrid, SolveMode::AllInstances);
+    while all_instances.solve_iteration().unwrap() {}
+
+    assert_eq!(count_digits(&first_instance.sgrid), 81);
+    assert!(all_instances.sgrid == first_instance.sgrid);
+    assert!(all_instances.current_step < first_instance.current_step);
+}
+
+// Removes a candidate that belongs to the solution to exercise verification.
+struct UnsoundSolver;
+
+impl SudokuSolveMethod for UnsoundSolver {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
+        let mut solution = sgrid.clone();
+        solution.backtrack_fill();
+        let (row, col) = (0, 0);
+        Ok(Some((
+            vec![SolverAction::CandidateReduction(row, col, solution.grid[row][col])],
+            vec![VisualizerUpdate::SetTitle("Unsound".to_string())],
+        )))
+    }
+}
+
+#[test]
+fn test_verification_accepts_sound_solvers() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut solver = hidden_singles_manager(sgrid, SolveMode::AllInstances);
+    solver.enable_verification();
+    while solver.solve_iteration().unwrap() {}
+    assert_eq!(count_digits(&solver.sgrid), 81);
+}
+
+#[test]
+fn test_verification_reports_unsound_action() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut solver = SudokuSolverManager::new(sgrid.clone());
+    solver.add_solver(Box::new(UnsoundSolver));
+    solver.enable_verification();
+
+    match solver.solve_iteration() {
+        Err(SolverError::UnsoundAction(violation)) => {
+            assert_eq!(violation.technique, "Unsound");
+            assert!(violation.sgrid == sgrid);
+            assert!(violation.to_string().starts_with("Unsound action from technique [Unsound]: remove candidate 6 from A,1."));
+        },
+        _ => panic!("Expected the unsound action to be reported."),
+    }
+    // The offending action is not applied
+    assert!(solver.sgrid.candidates[0][0].contains(&6));
+}
+
+// Removes a candidate and then places a digit that is not a candidate, so the batch fails halfway.
+struct BrokenBatchSolver;
+
+impl SudokuSolveMethod for BrokenBatchSolver {
+    fn apply(&self, sgrid: &SudokuGrid) -> Result<Option<SolverResult>, SolverError> {
+        let removed = *sgrid.candidates[0][0].iter().next().unwrap();
+        let invalid = (1..=9).find(|digit| !sgrid.candidates[0][1].contains(digit)).unwrap();
+        Ok(Some((
+            vec![SolverAction::CandidateReduction(0, 0, removed), SolverAction::DigitSolve(0, 1, invalid)],
+            vec![VisualizerUpdate::SetTitle("Broken".to_string())],
+        )))
+    }
+}
+
+#[test]
+fn test_failed_step_leaves_manager_unchanged() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut solver = SudokuSolverManager::new(sgrid.clone());
+    solver.add_solver(Box::new(BrokenBatchSolver));
+    solver.set_collect_statistics(true);
+
+    assert!(matches!(solver.solve_iteration(), Err(SolverError::InvalidPlacement(0, 1, _))));
+    assert!(solver.sgrid == sgrid);
+    assert_eq!(solver.current_step, 0);
+    assert!(solver.visualizers_per_step.is_empty());
+    assert!(solver.techniques_per_step.is_empty());
+    assert_eq!(solver.statistics[0].calls, 0);
+}
+
+#[test]
+fn test_empty_cell_is_reported() {
+    let mut sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    sgrid.candidates[0][0].clear();
+    let mut solver = hidden_singles_manager(sgrid, SolveMode::FirstInstance);
+
+    match solver.solve_iteration() {
+        Err(SolverError::InvalidState(violations)) => assert_eq!(violations, vec![GridViolation::EmptyCell(0, 0)]),
+        _ => panic!("Expected the empty cell to be reported."),
+    }
+}
+
+#[test]
+fn test_digit_missing_from_unit_is_reported() {
+    let mut sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    for col in 0..9 {
+        sgrid.candidates[0][col].remove(&7);
+    }
+    let mut solver = hidden_singles_manager(sgrid, SolveMode::FirstInstance);
+
+    match solver.solve_iteration() {
+        Err(SolverError::InvalidState(violations)) => {
+            assert_eq!(violations.len(), 1);
+            assert!(matches!(violations[0], GridViolation::DigitMissingFromUnit { digit: 7, unit_type: UnitType::Row, unit_index: 0, .. }));
+        },
+        _ => panic!("Expected the missing digit to be reported."),
+    }
+}
+
+#[test]
+fn test_statistics_are_collected() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut solver = hidden_singles_manager(sgrid, SolveMode::FirstInstance);
+    solver.set_collect_statistics(true);
+    while solver.solve_iteration().unwrap() {}
+
+    let stats = &solver.statistics[0];
+    assert_eq!(stats.name, "HiddenSinglesSolver");
+    assert_eq!(stats.calls, solver.current_step);
+    assert_eq!(stats.hits, solver.current_step - 1);
+}
+
+#[test]
+fn test_statistics_are_opt_in() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut solver = hidden_singles_manager(sgrid, SolveMode::FirstInstance);
+    while solver.solve_iteration().unwrap() {}
+
+    assert_eq!(solver.statistics[0].calls, 0);
+}
+
+#[test]
+fn test_adaptive_mode_skips_unchanged_state() {
+    // No hidden singles are available in this grid
+    let sgrid = SudokuGrid::from_string("783294156006813729912000483090000000030070000820309600008601000160930008009085061");
+    let mut solver = hidden_singles_manager(sgrid, SolveMode::FirstInstance);
+    solver.set_adaptive(true);
+    solver.set_collect_statistics(true);
+
+    assert!(!solver.solve_iteration().unwrap());
+    assert!(!solver.solve_iteration().unwrap());
+    assert_eq!(solver.statistics[0].calls, 1);
+    assert_eq!(solver.statistics[0].skipped, 1);
+}
+
+#[test]
+fn test_solve_summary() {
+    let sgrid = SudokuGrid::from_string("000105000140000670080002400063070010900000003010090520007200080026000035000409000");
+    let mut manager = SudokuSolverManager::new(sgrid);
+    for (_, solver) in default_solvers() {
+        manager.add_solver(solver);
+    }
+    let summary = manager.solve().unwrap();
+
+    assert!(summary.solved);
+    assert_eq!(summary.steps, manager.techniques_per_step.iter().flatten().count());
+    assert_eq!(summary.difficulty(), Some(Difficulty::Beginner));
+}
+
+#[test]
+fn test_adaptive_mode_skips_during_solve() {
+    let sgrid = SudokuGrid::from_string("800005007030000060207000080000000000000070419083006000401200000002068001000140250");
+    let mut manager = SudokuSolverManager::new(sgrid);
+    for (_, solver) in default_solvers() {
+        manager.add_solver(solver);
+    }
+    manager.set_adaptive(true);
+    manager.set_collect_statistics(true);
+    let summary = manager.solve().unwrap();
+
+    assert!(summary.solved);
+    // The chain solvers are keyed on their links, so eliminations elsewhere do not force another search
+    for name in ["XChainSolver", "XYChainSolver"] {
+        let statistics = manager.statistics.iter().find(|statistics| statistics.name == name).unwrap();
+        assert!(statistics.skipped > 0, "{} was never skipped", name);
+    }
+}
+
+#[test]
+fn test_new_grid_resets_statistics_and_cache() {
+    // No hidden singles are available in this grid
+    let sgrid = SudokuGrid::from_string("783294156006813729912000483090000000030070000820309600008601000160930008009085061");
+    let mut solver = hidden_singles_manager(sgrid.clone(), SolveMode::FirstInstance);
+    solver.set_adaptive(true);
+    solver.set_collect_statistics(true);
+    assert!(!solver.solve_iteration().unwrap());
+
+    solver.new_grid(sgrid);
+    assert_eq!(solver.statistics[0].calls, 0);
+    assert!(!solver.solve_iteration().unwrap());
+    assert_eq!(solver.statistics[0].calls, 1);
+    assert_eq!(solver.statistics[0].skipped, 0);
+}
+
+#[test]
+fn test_default_solvers_are_sorted_by_tier() {
+    let tiers: Vec<Difficulty> = default_solvers().into_iter().map(|(tier, _)| tier).collect();
+    assert!(tiers.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_easier_technique_is_used_first() {
+    // Both an XY-Chain (expert) and an XYZ-Wing (hard) apply here
+    let position = SudokuGrid::from_candidate_string("
+        5 7 6 2 9 4 [18] [18] 3
+        [34] [14] [13] 7 8 6 9 2 5
+        8 9 2 5 1 3 6 4 7
+        7 3 8 6 2 9 [14] 5 [14]
+        1 5 4 3 7 8 2 9 6
+        6 2 9 4 5 1 7 3 8
+        9 8 7 1 4 5 3 6 2
+        [23] [14] [35] 9 6 [27] [58] [78] [14]
+        [24] 6 [15] 8 3 [27] [145] [17] 9").unwrap();
+    let xy_chain = default_solvers().into_iter().find(|(_, solver)| solver.name() == "XYChainSolver").unwrap().1;
+    assert!(xy_chain.apply(&position).unwrap().is_some());
+
+    let mut manager = SudokuSolverManager::new(position);
+    for (_, solver) in default_solvers() {
+        manager.add_solver(solver);
+    }
+    assert!(manager.solve_iteration().unwrap());
+    assert_eq!(manager.techniques_per_step, vec![Some("XYZWingSolver")]);
+}
@@ -0,0 +1,63 @@
+extern crate sudoku_generator;
+
+use sudoku_generator::layout::{ChainPath, GridLayout, Point};
+use sudoku_generator::raster::Canvas;
+use sudoku_generator::solvers::solver_manager::SudokuSolverManager;
+use sudoku_generator::solvers::x_wing_solver::XWingSolver;
+use sudoku_generator::svg_renderer::SvgRenderer;
+use sudoku_generator::sudoku_grid::SudokuGrid;
+use sudoku_generator::theme::Theme;
+
+#[test]
+fn test_chain_path_bends_around_candidates() {
+    let layout = GridLayout::new(0.0, 0.0, 800.0, 800.0);
+    assert!(matches!(layout.chain_path((0, 0, 1), (0, 2, 1), vec![(0, 0, 1), (0, 2, 1)]), ChainPath::Line(_, _)));
+    assert!(matches!(layout.chain_path((0, 0, 1), (0, 2, 1), vec![(0, 0, 1), (0, 1, 1), (0, 2, 1)]), ChainPath::Curve(_, _, _)));
+    // Candidates beside the line do not matter
+    assert!(matches!(layout.chain_path((0, 0, 1), (0, 2, 1), vec![(0, 1, 4)]), ChainPath::Line(_, _)));
+}
+
+#[test]
+fn test_cell_at() {
+    let layout = GridLayout::below_header(800, 800);
+    assert_eq!(layout.cell_at(layout.cell_center(0, 0)), Some((0, 0)));
+    assert_eq!(layout.cell_at(layout.cell_center(4, 7)), Some((4, 7)));
+    assert_eq!(layout.cell_at(layout.cell_center(8, 8)), Some((8, 8)));
+    assert_eq!(layout.cell_at(Point::new(1.0, 1.0)), None);
+}
+
+#[test]
+fn test_header_lines_fit_the_width() {
+    let title = "Finned Swordfish with a remarkably long title";
+    let description = "A description that is far too long for a narrow image and has to wrap over several lines, Supercalifragilisticexpialidocious";
+    for (width, height) in [(300, 300), (150, 600), (800, 800)] {
+        let lines = GridLayout::header_lines(title, description, width, height);
+        assert!(lines.len() >= 2);
+        for line in lines {
+            assert!(!line.text.is_empty());
+            assert!(Canvas::measure_text(&line.text, line.size) <= width, "\"{}\" is wider than {}", line.text, width);
+        }
+    }
+}
+
+#[test]
+fn test_render_step() {
+    let xwing = SudokuGrid::from_string("093004560060003140004608309981345000347286951652070483406002890000400010029800034");
+    let mut manager = SudokuSolverManager::new(xwing);
+    manager.add_solver(Box::new(XWingSolver));
+    assert!(manager.solve_iteration().unwrap());
+    let step = &manager.visualizers_per_step[0][1];
+
+    let mut renderer = SvgRenderer::new(800, 600);
+    let svg = renderer.render(step);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"600\""));
+    assert!(svg.contains(">X-Wing - Step 1</text>"));
+    assert_eq!(svg, renderer.render(step));
+    // Both diagonals of the X-Wing are drawn as chains
+    assert!(svg.matches("stroke=\"rgb(123,126,143)\"").count() >= 2);
+
+    renderer.set_theme(Theme::dark());
+    let dark = renderer.render(step);
+    assert!(dark.contains("fill=\"rgb(30,30,36)\""));
+    assert!(!dark.contains("rgb(123,126,143)"));
+}